@@ -0,0 +1,58 @@
+//! Counts how many times the allocator is called while building a list, to
+//! show that every pushed value costs a single allocation for its node, and
+//! that popping and pushing again reuses the freed nodes.
+//!
+//! Run with `cargo run --release --example alloc_count`.
+use linked_list::data_structure::linked_list::LinkedList;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Number of values pushed onto the list.
+const PUSH_COUNT: usize = 10_000;
+
+/// Number of times a value is popped and pushed again.
+const CHURN_COUNT: usize = 10_000;
+
+/// Allocator that counts every allocation before handing it to the system allocator.
+struct CountingAlloc;
+
+/// Number of allocations made so far.
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+// SAFETY: every call is forwarded to the system allocator unchanged
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        // SAFETY: the caller upholds the contract of GlobalAlloc::alloc
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: the caller upholds the contract of GlobalAlloc::dealloc
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn main() {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let mut list = LinkedList::new();
+    for value in 0..PUSH_COUNT {
+        list.push_back(value);
+    }
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+
+    // pop and push again, the freed nodes are handed out from the pool
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    for _ in 0..CHURN_COUNT {
+        let value = list.pop_front().unwrap();
+        list.push_back(value);
+    }
+    let churn_allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+    drop(list);
+
+    println!("{PUSH_COUNT} pushes made {allocations} allocations");
+    println!("{CHURN_COUNT} pops and pushes made {churn_allocations} allocations");
+}
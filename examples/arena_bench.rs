@@ -0,0 +1,30 @@
+//! Compares how long it takes to build and drop a large list when every
+//! node is allocated on its own, and when the nodes come from an arena.
+//!
+//! Run with `cargo run --release --example arena_bench`.
+use linked_list::data_structure::linked_list::LinkedList;
+use std::time::{Duration, Instant};
+
+/// Number of values pushed onto each list.
+const PUSH_COUNT: usize = 1_000_000;
+
+/// Pushes `PUSH_COUNT` values onto the list, then drops it.
+///
+/// # Returns
+/// - How long building and dropping the list took.
+fn time_pushes(mut list: LinkedList<usize>) -> Duration {
+    let start = Instant::now();
+    for value in 0..PUSH_COUNT {
+        list.push_back(value);
+    }
+    drop(list);
+    start.elapsed()
+}
+
+fn main() {
+    let per_node = time_pushes(LinkedList::new());
+    let arena = time_pushes(LinkedList::with_arena());
+
+    println!("{PUSH_COUNT} pushes with per-node allocation: {per_node:?}");
+    println!("{PUSH_COUNT} pushes with an arena:             {arena:?}");
+}
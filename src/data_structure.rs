@@ -1 +1,2 @@
+mod arena;
 pub mod linked_list;
@@ -0,0 +1,177 @@
+//! Defines a bump arena that hands out memory for many nodes at once, so
+//! building a large list makes far fewer calls to the allocator.
+use std::alloc::{self, Layout};
+use std::ptr::NonNull;
+use std::sync::Arc;
+
+/// Number of nodes the first chunk has room for. Every chunk after it is
+/// twice as big as the one before.
+const FIRST_CHUNK_CAPACITY: usize = 64;
+
+/// Block of memory with room for `capacity` nodes.
+struct Chunk<NodeType> {
+    start: NonNull<NodeType>,
+    capacity: usize,
+}
+
+impl<NodeType> Chunk<NodeType> {
+    /// Allocates a chunk with room for `capacity` nodes.
+    ///
+    /// # Params
+    /// - `capacity` - The number of nodes the chunk has room for. Must not be 0.
+    fn new(capacity: usize) -> Self {
+        let layout = Layout::array::<NodeType>(capacity).expect("arena chunk is too large");
+        assert!(layout.size() > 0, "arena nodes must not be zero sized");
+
+        // SAFETY: the layout has a non-zero size
+        let start = unsafe { alloc::alloc(layout) }.cast::<NodeType>();
+        let Some(start) = NonNull::new(start) else {
+            alloc::handle_alloc_error(layout);
+        };
+
+        Self { start, capacity }
+    }
+
+    /// Checks if a node lives inside of this chunk.
+    fn contains(&self, node_ptr: NonNull<NodeType>) -> bool {
+        let start = self.start.as_ptr() as usize;
+        let end = start + self.capacity * size_of::<NodeType>();
+        (start..end).contains(&(node_ptr.as_ptr() as usize))
+    }
+}
+
+impl<NodeType> Drop for Chunk<NodeType> {
+    fn drop(&mut self) {
+        // the nodes were already dropped by the lists that held them,
+        // so only the memory is left to free
+        // SAFETY: the chunk was allocated with this same layout in new()
+        unsafe {
+            let layout = Layout::array::<NodeType>(self.capacity).unwrap_unchecked();
+            alloc::dealloc(self.start.as_ptr().cast(), layout);
+        }
+    }
+}
+
+// SAFETY: a chunk is only memory, the nodes inside of it are owned by lists
+unsafe impl<NodeType> Send for Chunk<NodeType> {}
+// SAFETY: a chunk is only memory, the nodes inside of it are owned by lists
+unsafe impl<NodeType> Sync for Chunk<NodeType> {}
+
+/// Bump arena that nodes can be allocated from.
+///
+/// Nodes are never handed back to the arena one at a time. Instead, a chunk is
+/// freed once no arena holds it anymore. Chunks are shared between the arenas
+/// of different lists, so a node that moves into another list keeps the memory
+/// it lives in alive.
+pub(crate) struct Arena<NodeType> {
+    // every chunk that a node of the owning list may live in
+    chunks: Vec<Arc<Chunk<NodeType>>>,
+    // index of the chunk that new nodes are handed out from
+    current: Option<usize>,
+    // number of nodes already handed out from the current chunk
+    used: usize,
+    // whether the owning list allocates its nodes from here,
+    // or only uses the arena to keep chunks alive
+    allocating: bool,
+}
+
+impl<NodeType> Arena<NodeType> {
+    /// Creates an empty arena that nodes can be allocated from.
+    pub(crate) fn new() -> Self {
+        Self {
+            chunks: Vec::new(),
+            current: None,
+            used: 0,
+            allocating: true,
+        }
+    }
+
+    /// Creates an arena whose first chunk has room for exactly `capacity` nodes,
+    /// so that many nodes allocated in a row sit next to each other in memory.
+    ///
+    /// # Params
+    /// - `capacity` - The number of nodes the first chunk has room for.
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        if capacity == 0 {
+            return Self::new();
+        }
+
+        Self {
+            chunks: vec![Arc::new(Chunk::new(capacity))],
+            current: Some(0),
+            ..Self::new()
+        }
+    }
+
+    /// Creates an empty arena that is only used to keep chunks alive.
+    pub(crate) fn retaining() -> Self {
+        Self {
+            allocating: false,
+            ..Self::new()
+        }
+    }
+
+    /// Creates an arena that keeps the same chunks alive as this one. If this
+    /// arena allocates, the new one does too, but from its own chunks.
+    pub(crate) fn share(&self) -> Self {
+        Self {
+            chunks: self.chunks.clone(),
+            current: None,
+            used: 0,
+            allocating: self.allocating,
+        }
+    }
+
+    /// Checks if the owning list should allocate its nodes from this arena.
+    pub(crate) fn is_allocating(&self) -> bool {
+        self.allocating
+    }
+
+    /// Keeps every chunk of another arena alive as well, so nodes from it can
+    /// be moved into the list that owns this arena.
+    pub(crate) fn adopt(&mut self, other: &Arena<NodeType>) {
+        for chunk in &other.chunks {
+            if !self.chunks.iter().any(|own| Arc::ptr_eq(own, chunk)) {
+                self.chunks.push(Arc::clone(chunk));
+            }
+        }
+    }
+
+    /// Checks if a node lives inside of one of the chunks of this arena.
+    pub(crate) fn contains(&self, node_ptr: NonNull<NodeType>) -> bool {
+        self.chunks.iter().any(|chunk| chunk.contains(node_ptr))
+    }
+
+    /// Moves a node into the arena.
+    ///
+    /// # Params
+    /// - `node` - The node to move into the arena.
+    ///
+    /// # Returns
+    /// - A pointer to the node inside of the arena.
+    pub(crate) fn alloc(&mut self, node: NodeType) -> NonNull<NodeType> {
+        let current = match self.current {
+            Some(idx) if self.used < self.chunks[idx].capacity => idx,
+            _ => {
+                // out of room, grab a new chunk twice as big as the last one
+                let capacity = match self.current {
+                    Some(idx) => self.chunks[idx].capacity * 2,
+                    None => FIRST_CHUNK_CAPACITY,
+                };
+                self.chunks.push(Arc::new(Chunk::new(capacity)));
+                self.used = 0;
+                self.current = Some(self.chunks.len() - 1);
+                self.chunks.len() - 1
+            }
+        };
+
+        // SAFETY: used is less than the capacity of the chunk, and this slot
+        // has never been handed out before
+        unsafe {
+            let node_ptr = self.chunks[current].start.add(self.used);
+            node_ptr.write(node);
+            self.used += 1;
+            node_ptr
+        }
+    }
+}
@@ -1,181 +1,292 @@
 //! Defines a simple linked list. Used to learn how to utilize the
 //! `unsafe` keyword.
+use super::arena::Arena;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::{Index, IndexMut};
 use std::ptr::NonNull;
 
+/// Most freed nodes a list keeps around to reuse for later pushes.
+const POOL_CAPACITY: usize = 32;
+
+/// Creates a `LinkedList` holding the given values in the order they are written.
+///
+/// Expands to `LinkedList::new()` followed by a `push_back` for each value.
+#[macro_export]
+macro_rules! linked_list {
+    () => {
+        $crate::data_structure::linked_list::LinkedList::new()
+    };
+    ($($value:expr),+ $(,)?) => {{
+        let mut list = $crate::data_structure::linked_list::LinkedList::new();
+        $(list.push_back($value);)+
+        list
+    }};
+}
+
+/// Errors that can occur when operating on a `LinkedList`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkedListError {
+    /// The index does not refer to a position in the list.
+    IndexOutOfBounds { index: usize, len: usize },
+    /// The operation needs a value, but the list is empty.
+    EmptyList,
+    /// The value that was searched for is not in the list.
+    NotFound,
+}
+
+impl fmt::Display for LinkedListError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LinkedListError::IndexOutOfBounds { index, len } => {
+                write!(
+                    f,
+                    "index out of bounds: the len is {len} but the index is {index}"
+                )
+            }
+            LinkedListError::EmptyList => write!(f, "the list is empty"),
+            LinkedListError::NotFound => write!(f, "the value was not found in the list"),
+        }
+    }
+}
+
+impl std::error::Error for LinkedListError {}
+
 /// Linked List struct that can hold any type of value.
 ///
-/// We essentially treat the first node of the LinkedList as the head.
-/// It will never contain a value, it will just point to the rest of the list.
+/// The list only points at its first and last nodes, every value lives in a
+/// `Node` of its own.
+///
+/// Up to `POOL_CAPACITY` nodes freed by removing values are kept in a pool and
+/// reused by later pushes, so a list that shrinks and grows again doesn't have
+/// to allocate new nodes. [`LinkedList::drain_pool`] hands that memory back early.
 pub struct LinkedList<StoreType> {
-    value: Option<Box<StoreType>>,
-    // next is NonNull because we need raw pointers
-    //to be able to navigate through the linked list mutably.
-    next: Option<NonNull<LinkedList<StoreType>>>,
+    // first node of the list. NonNull because we need raw pointers
+    // to be able to navigate through the linked list mutably.
+    head: Option<NonNull<Node<StoreType>>>,
+    // last node of the list, so we can push to the back without walking the list.
+    tail: Option<NonNull<Node<StoreType>>>,
+    // number of values in the list.
+    length: usize,
+    // arena the nodes of this list may live in, None if they are all allocated on their own.
+    arena: Option<Arena<Node<StoreType>>>,
+    // stack of freed heap nodes to reuse before allocating new ones, linked
+    // through their next pointers. Their values were already moved out.
+    free: Option<NonNull<Node<StoreType>>>,
+    // number of nodes in the free stack, at most POOL_CAPACITY.
+    free_length: usize,
+    // the list owns its nodes, and through them its values
+    marker: PhantomData<Box<Node<StoreType>>>,
+}
+
+/// A single link of a `LinkedList`, holding one value. Never leaves this
+/// module, so code outside of it can't name a node:
+///
+/// ```compile_fail
+/// use linked_list::data_structure::linked_list::Node;
+/// ```
+struct Node<StoreType> {
+    value: StoreType,
+    next: Option<NonNull<Node<StoreType>>>,
 }
 
 impl<StoreType> LinkedList<StoreType> {
     /// Create a new empty list.
     pub fn new() -> Self {
         Self {
-            value: None,
-            next: None,
+            head: None,
+            tail: None,
+            length: 0,
+            arena: None,
+            free: None,
+            free_length: 0,
+            marker: PhantomData,
         }
     }
 
-    /// Remove the value at the specified index.
+    /// Create a new empty list that allocates its nodes from an arena.
+    ///
+    /// Nodes are handed out from large chunks of memory instead of being
+    /// allocated one at a time, which makes building large lists much cheaper.
+    /// Removed nodes are only reused through the pool, and their memory is only
+    /// freed once the list and every list its nodes were moved into have been dropped.
+    pub fn with_arena() -> Self {
+        let mut list = Self::new();
+        list.arena = Some(Arena::new());
+        list
+    }
+
+    /// Creates a list by calling `f` until it returns `None`, pushing every
+    /// value it returns onto the end of the list in order.
     ///
     /// # Params
-    /// - `idx` - The index to remove the value at.
+    /// - `f` - Closure that returns the next value, or `None` once there are no more.
     ///
     /// # Returns
-    /// - `Ok(())` if the value could be removed, `Err(())` otherwise.
-    pub fn remove(&mut self, idx: usize) -> Result<(), ()> {
-        let mut cur_node; // our current value
-
-        if idx == 0 {
-            // remove the head of the list
-            if let Some(temp_val) = self.next {
-                cur_node = temp_val.as_ptr();
-            } else {
-                return Err(()); // expected a value but got none
-            }
-
-            // SAFETY: cur_node is always Some value
-            unsafe {
-                // set to what the cur_node has as its next node.
-                // Could be Some or None
-                self.next = (*cur_node).next;
+    /// - A list holding every value `f` returned before the first `None`.
+    pub fn from_fn<F: FnMut() -> Option<StoreType>>(mut f: F) -> Self {
+        let mut list = Self::new();
+        while let Some(value) = f() {
+            list.push_back(value);
+        }
+        list
+    }
 
-                // set the next node of the current node to None so we do not accidentally deallocate the rest of the list
-                (*cur_node).next = None;
+    /// Creates a list holding `n` copies of `value`, like `vec![value; n]`.
+    /// The last copy is `value` itself, so it is only cloned `n - 1` times.
+    ///
+    /// # Params
+    /// - `value` - The value to fill the list with.
+    /// - `n` - The number of values in the list, 0 gives an empty list.
+    ///
+    /// # Returns
+    /// - A list holding `n` values equal to `value`.
+    pub fn repeat(value: StoreType, n: usize) -> Self
+    where
+        StoreType: Clone,
+    {
+        let mut list = Self::new();
+        if n == 0 {
+            return list;
+        }
 
-                // drop this node
-                drop(Box::from_raw(cur_node));
-            }
-        } else {
-            // remove some node in the middle/end of the list
+        for _ in 1..n {
+            list.push_back(value.clone());
+        }
+        list.push_back(value);
+        list
+    }
 
-            if let Some(temp_val) = self.next {
-                cur_node = temp_val.as_ptr();
-            } else {
-                return Err(()); // fail, empty list
-            }
-
-            let mut cur_idx = 0;
-            // keep going until we are at the value right before
-            while cur_idx < idx - 1 {
-                // SAFETY: cur_node is always Some value
-                unsafe {
-                    if let Some(temp_val) = (*cur_node).next {
-                        cur_node = temp_val.as_ptr();
-                    } else {
-                        return Err(()); // fail, expected a value to be here and there wasn't
-                    }
-                }
-                cur_idx += 1;
-            }
+    /// Creates a new empty list that keeps the same arena chunks alive as this
+    /// one, so nodes can be moved from this list into it.
+    fn new_sibling(&self) -> Self {
+        let mut list = Self::new();
+        list.arena = self.arena.as_ref().map(Arena::share);
+        list
+    }
 
-            // now we have the node right before the value to remove
-            let node_to_remove;
+    /// Allocates a new node holding `value`. Reuses a pooled node if there is
+    /// one, and otherwise allocates from the arena if this list has one.
+    ///
+    /// # Params
+    /// - `value` - The value the node holds.
+    /// - `next` - The node the new node points to.
+    ///
+    /// # Returns
+    /// - A pointer to the new node.
+    fn alloc_node(
+        &mut self,
+        value: StoreType,
+        next: Option<NonNull<Node<StoreType>>>,
+    ) -> NonNull<Node<StoreType>> {
+        let new_node = Node { value, next };
 
-            // SAFETY: cur_node is always Some value
+        if let Some(pooled_node) = self.free {
+            // SAFETY: pooled nodes are valid memory for a node, on the heap or in
+            // a chunk this list's arena keeps alive. Only their value was moved
+            // out, so a whole new node can be written over them
             unsafe {
-                if let Some(temp_val) = (*cur_node).next {
-                    node_to_remove = temp_val.as_ptr();
-                } else {
-                    return Err(()); // expected the next value to exist, but it doesn't
-                }
-
-                // we have a value to point to
-                (*cur_node).next = (*node_to_remove).next;
-
-                // so we do not accidentally deallocate the rest of the list
-                (*node_to_remove).next = None;
-
-                // drop the node to remove now
-                drop(Box::from_raw(node_to_remove));
+                self.free = (*pooled_node.as_ptr()).next;
+                self.free_length -= 1;
+                pooled_node.as_ptr().write(new_node);
             }
+            return pooled_node;
         }
 
-        Ok(())
+        match &mut self.arena {
+            Some(arena) if arena.is_allocating() => arena.alloc(new_node),
+            // allocate on the heap. Box::into_raw hands over a raw pointer
+            // directly, without a `&mut` in between whose borrow could linger
+            // SAFETY: Box::into_raw never returns a null pointer
+            _ => unsafe { NonNull::new_unchecked(Box::into_raw(Box::new(new_node))) },
+        }
     }
 
-    /// Pushes a value at the beginning of the list.
-    /// Sets this value as the new head.
+    /// Frees a node that was allocated by `alloc_node` and returns its value.
+    /// The node goes into the pool to be reused, unless it is already full.
     ///
     /// # Params
-    /// - `value` - The value to push to the front of the list.
-    pub fn push_front(&mut self, value: StoreType) {
-        // allocate on the heap
-        let new_node = Box::new(LinkedList {
-            value: Some(Box::new(value)),
-            next: None,
-        });
-        if self.next.is_none() {
-            // this is the new head of the list
-            self.next = Some(Box::leak(new_node).into());
-        } else {
-            // the list has something next, so we need to do some magic
+    /// - `node_ptr` - The node to free.
+    ///
+    /// # Safety
+    /// `node_ptr` must be a node of this list that has already been unlinked,
+    /// and must not be used again afterwards.
+    unsafe fn free_node(&mut self, node_ptr: NonNull<Node<StoreType>>) -> StoreType {
+        let in_arena = self
+            .arena
+            .as_ref()
+            .is_some_and(|arena| arena.contains(node_ptr));
 
-            let mut new_node_ptr: NonNull<LinkedList<StoreType>> = Box::leak(new_node).into();
-            // SAFETY: new_node_ptr is always valid
-            unsafe {
-                // new node should point to the current head
-                new_node_ptr.as_mut().next = self.next;
+        // SAFETY: the caller guarantees the node is valid and unlinked
+        unsafe {
+            if self.free_length < POOL_CAPACITY {
+                // move only the value out, so the pool doesn't keep it alive,
+                // and keep the memory to reuse. Arena nodes stay valid as long
+                // as the arena of this list keeps their chunk alive.
+                let value = std::ptr::read(&raw const (*node_ptr.as_ptr()).value);
+                (&raw mut (*node_ptr.as_ptr()).next).write(self.free);
+                self.free = Some(node_ptr);
+                self.free_length += 1;
+                value
+            } else if in_arena {
+                // move the node out, the arena frees its memory later
+                std::ptr::read(node_ptr.as_ptr()).value
+            } else {
+                // take back ownership of the node so it is deallocated
+                Box::from_raw(node_ptr.as_ptr()).value
             }
-            // head is now the new pointer
-            self.next = Some(new_node_ptr);
         }
     }
 
-    /// Pushes a value at the end of the list.
-    ///
-    /// # Params
-    /// - `value` - The value to push back.
-    pub fn push_back(&mut self, value: StoreType) {
-        if self.next.is_none() {
-            // empty list, push to the front
+    /// Deallocates every node kept in the pool for reuse. The values in the
+    /// list are untouched, later pushes just allocate new nodes again. Pooled
+    /// arena nodes are only dropped from the pool, the arena frees their memory.
+    pub fn drain_pool(&mut self) {
+        while let Some(pooled_node) = self.free {
+            let in_arena = self
+                .arena
+                .as_ref()
+                .is_some_and(|arena| arena.contains(pooled_node));
 
-            let new_node = Box::new(LinkedList {
-                value: Some(Box::new(value)),
-                next: None,
-            });
-            self.next = Some(Box::leak(new_node).into());
-        } else {
+            // SAFETY: pooled nodes are valid, and their values were already
+            // moved out. The ones outside of the arena are heap allocations
+            // made by Box::new in alloc_node, so only their memory is freed
+            // and no value is dropped
             unsafe {
-                // we already checked self.next to be some value
-                let mut cur_node_ptr = self.next.unwrap_unchecked().as_ptr();
-
-                // keep going until we are at the last node
-                while (*cur_node_ptr).next.is_some() {
-                    // we already checked that the next value is something
-                    cur_node_ptr = (*cur_node_ptr).next.unwrap_unchecked().as_ptr();
+                self.free = (*pooled_node.as_ptr()).next;
+                if !in_arena {
+                    std::alloc::dealloc(
+                        pooled_node.as_ptr().cast(),
+                        std::alloc::Layout::new::<Node<StoreType>>(),
+                    );
                 }
-
-                // allocate on the heap
-                let new_node = Box::new(LinkedList {
-                    value: Some(Box::new(value)),
-                    next: None,
-                });
-                // this is the new tail of the list
-                (*cur_node_ptr).next = Some(Box::leak(new_node).into());
             }
         }
+        self.free_length = 0;
     }
 
     /// Gets the node at the index provided, or None if it couldn't be found.
+    /// Index 0 is the first node, so it is `None` for an empty list without
+    /// reading past `self.head`.
+    ///
+    /// Only goes through raw pointers, so callers can write through the
+    /// returned node without invalidating any reference made along the way.
     ///
     /// # Returns
-    /// - Reference to `Some` value if it could be found, `None` otherwise.
-    fn get_node_at(&self, idx: usize) -> &Option<NonNull<LinkedList<StoreType>>> {
-        let mut cur_node = &self.next;
+    /// - `Some` node if it could be found, `None` otherwise.
+    fn get_node_at(&self, idx: usize) -> Option<NonNull<Node<StoreType>>> {
+        let mut cur_node = self.head;
         let mut cur_idx = 0;
 
         // keep going until we have our value or we reach a none
-        while cur_idx < idx && cur_node.is_some() {
-            // SAFETY: cur_node is always Some value
+        while cur_idx < idx {
+            let temp_val = cur_node?;
+
+            // SAFETY: temp_val is always a valid node
             unsafe {
-                cur_node = &cur_node.unwrap_unchecked().as_ref().next;
+                cur_node = (*temp_val.as_ptr()).next;
             }
             cur_idx += 1;
         }
@@ -183,44 +294,79 @@ impl<StoreType> LinkedList<StoreType> {
         cur_node
     }
 
-    /// Adds a value at the index provided. Places the new value before
-    /// the existing value in the list.
-    ///
-    /// # Param
-    /// - `value` - The value to add.
-    /// - `idx` - The index in the list to add the value at.
+    /// Checks if following the next pointers from the first node ever leads
+    /// back to an earlier node. A correct list never has a cycle, this is a
+    /// safety net for bugs in the unsafe code. Uses Floyd's tortoise and hare,
+    /// so it needs no extra memory.
     ///
     /// # Returns
-    /// - `OK(())` if the value could be added, `Err(())` otherwise.
-    pub fn add_at(&mut self, value: StoreType, idx: usize) -> Result<(), ()> {
-        if idx == 0 {
-            // push front
-            self.push_front(value);
-        } else {
-            // get the node before where we want to push
-            let before_node = self.get_node_at(idx - 1);
+    /// - `true` if the nodes form a cycle, `false` otherwise.
+    pub fn has_cycle(&self) -> bool {
+        let mut slow_node = self.head;
+        let mut fast_node = self.head;
 
-            if let Some(temp_val) = before_node {
-                let before_node_ptr = temp_val.as_ptr();
+        // SAFETY: every node reachable from the head is valid
+        unsafe {
+            loop {
+                // the hare moves two nodes for every one of the tortoise
+                let Some(fast_next) = fast_node.and_then(|temp_val| (*temp_val.as_ptr()).next)
+                else {
+                    return false;
+                };
+                fast_node = (*fast_next.as_ptr()).next;
+                slow_node = slow_node.and_then(|temp_val| (*temp_val.as_ptr()).next);
 
-                unsafe {
-                    let new_node = Box::new(LinkedList {
-                        value: Some(Box::new(value)),
-                        next: (*before_node_ptr).next,
-                    });
-
-                    // now we set the value
-                    (*before_node_ptr).next = Some(Box::leak(new_node).into());
+                if fast_node.is_some() && fast_node == slow_node {
+                    return true;
                 }
-            } else {
-                // we cannot push here
-                return Err(());
             }
         }
-        Ok(())
     }
 
-    /// Gets an element in the linked list at this index.
+    /// Checks that the cached length and tail agree with the nodes of the list.
+    /// Only called on the paths of mutating methods that already walk the whole
+    /// list, so the extra walk doesn't change their complexity. Paths that stop
+    /// walking early, like `remove` before the tail or `split_off`, skip it.
+    ///
+    /// # Panics
+    /// - If the nodes form a cycle.
+    /// - If the length or the tail is out of date.
+    #[cfg(debug_assertions)]
+    fn debug_assert_invariants(&self) {
+        #[cfg(test)]
+        tests::INVARIANT_CHECKS.set(tests::INVARIANT_CHECKS.get() + 1);
+
+        let mut node_count = 0;
+        let mut last_node = None;
+        let mut cur_node = self.head;
+
+        while let Some(temp_val) = cur_node {
+            node_count += 1;
+            if node_count > self.length {
+                // stop early, walking a cycle would never end. Only look for
+                // the cycle now that something is already wrong.
+                assert!(!self.has_cycle(), "the nodes of the list form a cycle");
+                break;
+            }
+            last_node = Some(temp_val);
+            // SAFETY: temp_val is always a valid node
+            cur_node = unsafe { (*temp_val.as_ptr()).next };
+        }
+
+        assert_eq!(
+            self.length, node_count,
+            "length does not match the number of nodes"
+        );
+        assert_eq!(self.tail, last_node, "tail does not point at the last node");
+    }
+
+    /// Release builds skip the check.
+    #[cfg(not(debug_assertions))]
+    #[inline(always)]
+    fn debug_assert_invariants(&self) {}
+
+    /// Gets an element in the linked list at this index. Index 0 is the first
+    /// value, so it is `None` for an empty list.
     ///
     /// # Params
     /// - `idx` - The index in the list to get the value from.
@@ -233,160 +379,4550 @@ impl<StoreType> LinkedList<StoreType> {
         match node {
             Some(temp_val) => {
                 // SAFETY: temp_val is always valid
-                unsafe { temp_val.as_ref().value.as_deref() }
+                unsafe { Some(&temp_val.as_ref().value) }
             }
             None => None,
         }
     }
-}
-
-impl<StoreType> Drop for LinkedList<StoreType> {
-    fn drop(&mut self) {
-        // since this is recursive, we will just drop our own stuff
-        if self.next.is_some() {
-            // we will have to drop NonNulls which are allocated on the heap
-            unsafe {
-                let next_node_ptr = self.next.unwrap_unchecked().as_ptr();
 
-                // we can drop the next node, calling its drop() function and continuing the loop
-                drop(Box::from_raw(next_node_ptr));
-            }
+    /// Gets an element like `get`, but gives up once reaching it would take
+    /// more than `max_steps` steps from the first node. Meant for debugging a
+    /// list whose nodes may be corrupted, since walking a cycle can't go on
+    /// for longer than the bound.
+    ///
+    /// # Params
+    /// - `idx` - The index in the list to get the value from.
+    /// - `max_steps` - The most next pointers to follow, index `n` takes `n` steps.
+    ///
+    /// # Returns
+    /// - `Some(&StoreType)` if the value could be found within `max_steps`
+    ///   steps, `None` otherwise.
+    pub fn get_bounded(&self, idx: usize, max_steps: usize) -> Option<&StoreType> {
+        if idx > max_steps {
+            return None;
         }
+
+        // reaching idx follows exactly idx next pointers, so the walk is bounded
+        self.get(idx)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Checks if the list holds a value equal to `target`.
+    ///
+    /// # Params
+    /// - `target` - The value to search for.
+    ///
+    /// # Returns
+    /// - `true` if a matching value was found, `false` otherwise.
+    pub fn contains(&self, target: &StoreType) -> bool
+    where
+        StoreType: PartialEq,
+    {
+        self.iter().any(|value| value == target)
+    }
 
-    #[test]
-    fn test_small_list_operations() {
-        let mut list = LinkedList::<i32>::new();
+    /// Checks if the values of `needle` appear one after another somewhere in
+    /// the list. Tries every starting node, so it takes `O(len() * needle.len())`.
+    ///
+    /// # Params
+    /// - `needle` - The run of values to search for.
+    ///
+    /// # Returns
+    /// - `true` if the run was found or `needle` is empty, `false` otherwise.
+    pub fn contains_subsequence(&self, needle: &[StoreType]) -> bool
+    where
+        StoreType: PartialEq,
+    {
+        let mut start_node = self.head;
+        let mut remaining = self.length;
 
-        list.push_front(4);
-        assert_eq!(4, *list.get(0).unwrap());
+        // stop once there are too few values left for the needle to fit
+        while remaining >= needle.len() {
+            let run = Iter {
+                next: start_node,
+                remaining,
+                back_nodes: None,
+                marker: PhantomData,
+            };
+            if run.take(needle.len()).eq(needle) {
+                return true;
+            }
 
-        list.push_front(5);
-        assert_eq!(5, *list.get(0).unwrap());
-        assert_eq!(4, *list.get(1).unwrap());
+            let Some(temp_val) = start_node else {
+                break;
+            };
+            // SAFETY: temp_val is always a valid node
+            start_node = unsafe { (*temp_val.as_ptr()).next };
+            remaining -= 1;
+        }
 
-        list.remove(0)
-            .expect("Removing this element should not cause an error");
-        assert_eq!(4, *list.get(0).unwrap());
+        false
+    }
 
-        list.push_back(100);
-        assert_eq!(4, *list.get(0).unwrap());
-        assert_eq!(100, *list.get(1).unwrap());
+    /// Checks if the list begins with the values of `prefix`.
+    ///
+    /// # Params
+    /// - `prefix` - The values to look for at the front of the list.
+    ///
+    /// # Returns
+    /// - `true` if the list starts with `prefix` or `prefix` is empty, `false` otherwise.
+    pub fn starts_with(&self, prefix: &[StoreType]) -> bool
+    where
+        StoreType: PartialEq,
+    {
+        prefix.len() <= self.length && self.iter().take(prefix.len()).eq(prefix)
     }
 
-    #[test]
-    fn test_larger_push_back() {
-        let mut list = LinkedList::<i32>::new();
+    /// Checks if the list ends with the values of `suffix`. The list can only be
+    /// walked forward, so it skips to index `len() - suffix.len()` and compares from there.
+    ///
+    /// # Params
+    /// - `suffix` - The values to look for at the back of the list.
+    ///
+    /// # Returns
+    /// - `true` if the list ends with `suffix` or `suffix` is empty, `false` otherwise.
+    pub fn ends_with(&self, suffix: &[StoreType]) -> bool
+    where
+        StoreType: PartialEq,
+    {
+        suffix.len() <= self.length && self.iter().skip(self.length - suffix.len()).eq(suffix)
+    }
 
-        list.push_back(100);
-        list.push_back(101);
-        list.push_back(102);
-        list.push_back(103);
-        list.push_back(104);
-        assert_eq!(100, *list.get(0).unwrap());
-        assert_eq!(101, *list.get(1).unwrap());
-        assert_eq!(102, *list.get(2).unwrap());
-        assert_eq!(103, *list.get(3).unwrap());
-        assert_eq!(104, *list.get(4).unwrap());
+    /// Splits off the first value of the list, without changing the list.
+    ///
+    /// # Returns
+    /// - `Some((first, rest))` with the first value and a view over every value
+    ///   after it, `None` if the list is empty.
+    pub fn split_first(&self) -> Option<(&StoreType, RestView<'_, StoreType>)> {
+        let first_node = self.head?;
+
+        // SAFETY: the first node is always valid when it is Some value
+        unsafe {
+            let first_node = first_node.as_ref();
+            let rest = RestView {
+                first: first_node.next,
+                length: self.length - 1,
+                marker: PhantomData,
+            };
+            Some((&first_node.value, rest))
+        }
     }
 
-    #[test]
-    fn test_add_at() {
-        let mut list = LinkedList::<i32>::new();
+    /// Splits off the last value of the list, without changing the list.
+    ///
+    /// # Returns
+    /// - `Some((last, rest))` with the last value and a view over every value
+    ///   before it, `None` if the list is empty.
+    pub fn split_last(&self) -> Option<(&StoreType, RestView<'_, StoreType>)> {
+        let last = self.last()?;
+        let rest = RestView {
+            first: self.head,
+            length: self.length - 1,
+            marker: PhantomData,
+        };
+        Some((last, rest))
+    }
 
-        list.add_at(1, 0).unwrap();
-        list.add_at(3, 1).unwrap();
-        list.add_at(4, 2).unwrap();
-        list.add_at(0, 0).unwrap();
-        list.add_at(2, 2).unwrap();
+    /// Gets an iterator over references to the values in the list.
+    ///
+    /// # Returns
+    /// - An `Iter` that yields the values from the front to the back of the list.
+    pub fn iter(&self) -> Iter<'_, StoreType> {
+        Iter {
+            next: self.head,
+            remaining: self.length,
+            back_nodes: None,
+            marker: PhantomData,
+        }
+    }
 
-        assert_eq!(0, *list.get(0).unwrap());
-        assert_eq!(1, *list.get(1).unwrap());
-        assert_eq!(2, *list.get(2).unwrap());
-        assert_eq!(3, *list.get(3).unwrap());
-        assert_eq!(4, *list.get(4).unwrap());
+    /// Gets the number of values stored in the list.
+    ///
+    /// # Returns
+    /// - The number of values in the list.
+    pub fn len(&self) -> usize {
+        self.length
     }
 
-    #[test]
-    fn test_remove() {
-        let mut list = LinkedList::<i32>::new();
+    /// Checks if the list has no values. Does not traverse the list.
+    ///
+    /// # Returns
+    /// - `true` if the list is empty, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
 
-        list.push_back(0);
-        list.push_back(1);
-        list.push_back(2);
-        list.push_back(3);
-        list.push_back(4);
+    /// Remove the value at the specified index and return it. Index 0 is the first value.
+    ///
+    /// # Params
+    /// - `idx` - The index to remove the value at.
+    ///
+    /// # Returns
+    /// - `Ok(StoreType)` with the removed value if it could be removed.
+    /// - `Err(LinkedListError::EmptyList)` if the list is empty, for any index including 0.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if `idx` is not less than `len()`.
+    pub fn remove(&mut self, idx: usize) -> Result<StoreType, LinkedListError> {
+        if self.head.is_none() {
+            return Err(LinkedListError::EmptyList); // expected a value but got none
+        } else if idx >= self.length {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index: idx,
+                len: self.length,
+            });
+        }
 
-        list.remove(0).unwrap();
-        list.remove(2).unwrap();
-        list.remove(2).unwrap();
-        list.remove(2)
-            .expect_err("Expected an error when deleting a value that doesn't exist");
+        if idx == 0 {
+            // remove the head of the list, without walking it
+            // SAFETY: the list is not empty, so it has a first node
+            return Ok(unsafe { self.unlink_after(None) });
+        }
 
-        assert_eq!(1, *list.get(0).unwrap());
-        assert_eq!(2, *list.get(1).unwrap());
+        // remove some node in the middle/end of the list, so we need the node right before it
+        // SAFETY: idx - 1 is within the list, and idx is within the list so
+        // the node before it has a next node
+        let value = unsafe {
+            let prev_node = self.get_node_at(idx - 1).unwrap_unchecked();
+            self.unlink_after(Some(prev_node))
+        };
+        if idx == self.length {
+            // the tail was removed, so the whole list was already walked
+            self.debug_assert_invariants();
+        }
+        Ok(value)
     }
 
-    #[test]
-    fn test_push_front() {
-        let mut list = LinkedList::<i32>::new();
+    /// Removes the first value equal to `target` and returns it, in a single
+    /// pass over the list.
+    ///
+    /// # Params
+    /// - `target` - The value to search for.
+    ///
+    /// # Returns
+    /// - `Some(StoreType)` with the removed value, `None` if no value equals `target`.
+    pub fn remove_first(&mut self, target: &StoreType) -> Option<StoreType>
+    where
+        StoreType: PartialEq,
+    {
+        let mut prev_node = None;
+        let mut cur_node = self.head;
 
-        list.push_front(4);
-        list.push_front(3);
-        list.push_front(2);
-        list.push_front(1);
-        list.push_front(0);
+        while let Some(temp_val) = cur_node {
+            // SAFETY: temp_val is always valid, and is the node after prev_node
+            unsafe {
+                if (*temp_val.as_ptr()).value == *target {
+                    let removed_tail = (*temp_val.as_ptr()).next.is_none();
+                    let value = self.unlink_after(prev_node);
+                    if removed_tail {
+                        // the whole list was already walked
+                        self.debug_assert_invariants();
+                    }
+                    return Some(value);
+                }
+                cur_node = (*temp_val.as_ptr()).next;
+            }
+            prev_node = Some(temp_val);
+        }
 
-        assert_eq!(0, *list.get(0).unwrap());
-        assert_eq!(1, *list.get(1).unwrap());
-        assert_eq!(2, *list.get(2).unwrap());
-        assert_eq!(3, *list.get(3).unwrap());
-        assert_eq!(4, *list.get(4).unwrap());
+        None
     }
 
-    #[test]
-    fn test_push_back() {
-        let mut list = LinkedList::<i32>::new();
+    /// Removes every value equal to `target` in a single pass over the list.
+    ///
+    /// # Params
+    /// - `target` - The value to search for.
+    ///
+    /// # Returns
+    /// - The number of values that were removed.
+    pub fn remove_all(&mut self, target: &StoreType) -> usize
+    where
+        StoreType: PartialEq,
+    {
+        let old_length = self.length;
+        self.retain(|value| value != target);
+        old_length - self.length
+    }
 
-        list.push_back(0);
-        list.push_back(1);
-        list.push_back(2);
-        list.push_back(3);
-        list.push_back(4);
+    /// Removes the value at the beginning of the list and returns it.
+    ///
+    /// # Returns
+    /// - `Some(StoreType)` if the list had a value, `None` otherwise.
+    pub fn pop_front(&mut self) -> Option<StoreType> {
+        let cur_node = self.head?.as_ptr();
 
-        assert_eq!(0, *list.get(0).unwrap());
-        assert_eq!(1, *list.get(1).unwrap());
-        assert_eq!(2, *list.get(2).unwrap());
-        assert_eq!(3, *list.get(3).unwrap());
-        assert_eq!(4, *list.get(4).unwrap());
+        // SAFETY: cur_node is always Some value
+        unsafe {
+            // set to what the cur_node has as its next node.
+            // Could be Some or None
+            self.head = (*cur_node).next;
+            if self.head.is_none() {
+                // we popped the only node
+                self.tail = None;
+            }
+
+            self.length -= 1;
+
+            // free the node and move its value out
+            Some(self.free_node(NonNull::new_unchecked(cur_node)))
+        }
+    }
+
+    /// Removes the value at the end of the list and returns it.
+    ///
+    /// # Returns
+    /// - `Some(StoreType)` if the list had a value, `None` otherwise.
+    pub fn pop_back(&mut self) -> Option<StoreType> {
+        let mut cur_node_ptr = self.head?.as_ptr();
+
+        // SAFETY: cur_node_ptr is always Some value
+        let value = unsafe {
+            if (*cur_node_ptr).next.is_none() {
+                // only one value, so the list is empty afterwards
+                self.head = None;
+                self.tail = None;
+                self.length -= 1;
+                return Some(self.free_node(NonNull::new_unchecked(cur_node_ptr)));
+            }
+
+            // we already checked that the next value is something
+            let mut next_node_ptr = (*cur_node_ptr).next.unwrap_unchecked().as_ptr();
+
+            // keep going until we are at the second-to-last node
+            while (*next_node_ptr).next.is_some() {
+                cur_node_ptr = next_node_ptr;
+                next_node_ptr = (*next_node_ptr).next.unwrap_unchecked().as_ptr();
+            }
+
+            // detach the tail from the list
+            (*cur_node_ptr).next = None;
+            self.tail = Some(NonNull::new_unchecked(cur_node_ptr));
+            self.length -= 1;
+
+            self.free_node(NonNull::new_unchecked(next_node_ptr))
+        };
+        self.debug_assert_invariants();
+        Some(value)
+    }
+
+    /// Pushes a value at the beginning of the list.
+    /// Sets this value as the new head.
+    ///
+    /// # Params
+    /// - `value` - The value to push to the front of the list.
+    pub fn push_front(&mut self, value: StoreType) {
+        // new node should point to the current head
+        let new_node_ptr = self.alloc_node(value, self.head);
+        if self.head.is_none() {
+            // this is the new head of the list, and also its tail
+            self.tail = Some(new_node_ptr);
+        }
+        // head is now the new pointer
+        self.head = Some(new_node_ptr);
+        self.length += 1;
+    }
+
+    /// Pushes a value at the end of the list.
+    ///
+    /// # Params
+    /// - `value` - The value to push back.
+    pub fn push_back(&mut self, value: StoreType) {
+        let new_node_ptr = self.alloc_node(value, None);
+
+        // SAFETY: the node was just allocated and isn't in any list yet
+        unsafe {
+            self.link_node_back(new_node_ptr);
+        }
+    }
+
+    /// Links a node that is not in any list onto the end of this list.
+    /// Keeps the tail and length of the list up to date.
+    ///
+    /// # Params
+    /// - `node_ptr` - The node to link, its next node is overwritten.
+    ///
+    /// # Safety
+    /// `node_ptr` must be a valid node that no list links to, and its memory
+    /// must stay alive as long as this list, either on the heap or in a chunk
+    /// of this list's arena.
+    unsafe fn link_node_back(&mut self, node_ptr: NonNull<Node<StoreType>>) {
+        // SAFETY: the caller guarantees node_ptr is valid, and the tail is
+        // always a valid node when it is Some value
+        unsafe {
+            (*node_ptr.as_ptr()).next = None;
+
+            if let Some(tail_ptr) = self.tail {
+                (*tail_ptr.as_ptr()).next = Some(node_ptr);
+            } else {
+                // empty list, push to the front
+                self.head = Some(node_ptr);
+            }
+        }
+
+        // this is the new tail of the list
+        self.tail = Some(node_ptr);
+        self.length += 1;
+    }
+
+    /// Detaches the first node from the list without freeing it. Keeps the
+    /// tail and length of the list up to date.
+    ///
+    /// # Returns
+    /// - `Some(NonNull<Node>)` with the detached node, `None` if the list is empty.
+    ///   Its memory must be kept alive by whoever links it next.
+    fn unlink_front_node(&mut self) -> Option<NonNull<Node<StoreType>>> {
+        let first_node = self.head?;
+
+        // SAFETY: the head is always a valid node when it is Some value
+        unsafe {
+            self.head = (*first_node.as_ptr()).next;
+        }
+        if self.head.is_none() {
+            self.tail = None;
+        }
+        self.length -= 1;
+
+        Some(first_node)
+    }
+
+    /// Clones every value of a slice onto the end of the list, in order.
+    /// Uses the cached tail, so the list is never traversed.
+    ///
+    /// # Params
+    /// - `items` - The values to clone onto the end of the list.
+    pub fn push_back_slice(&mut self, items: &[StoreType])
+    where
+        StoreType: Clone,
+    {
+        self.extend(items.iter().cloned());
+    }
+
+    /// Pushes a value at the end of the list and hands the list back, so
+    /// pushes can be chained, like `LinkedList::new().with(1).with(2)`.
+    ///
+    /// # Params
+    /// - `value` - The value to push back.
+    ///
+    /// # Returns
+    /// - The list with the value pushed back.
+    pub fn with(mut self, value: StoreType) -> Self {
+        self.push_back(value);
+        self
+    }
+
+    /// Links a new node holding `value` into the list right after `before_node_ptr`.
+    /// Keeps the tail and length of the list up to date.
+    ///
+    /// # Params
+    /// - `before_node_ptr` - The node to place the new node after.
+    /// - `value` - The value to add.
+    ///
+    /// # Safety
+    /// `before_node_ptr` must be a node of this list.
+    unsafe fn link_after(&mut self, before_node_ptr: *mut Node<StoreType>, value: StoreType) {
+        // SAFETY: the caller guarantees before_node_ptr is valid
+        unsafe {
+            let new_node_ptr = self.alloc_node(value, (*before_node_ptr).next);
+
+            // now we set the value
+            if (*before_node_ptr).next.is_none() {
+                self.tail = Some(new_node_ptr);
+            }
+            (*before_node_ptr).next = Some(new_node_ptr);
+        }
+        self.length += 1;
+    }
+
+    /// Unlinks the node after `prev_node` from the list, frees it, and returns its value.
+    /// Keeps the tail and length of the list up to date.
+    ///
+    /// # Params
+    /// - `prev_node` - The node before the one to unlink, or `None` to unlink the first node.
+    ///
+    /// # Safety
+    /// `prev_node` must be a node of this list, and must have a next node.
+    unsafe fn unlink_after(&mut self, prev_node: Option<NonNull<Node<StoreType>>>) -> StoreType {
+        // SAFETY: the caller guarantees prev_node and its next node are valid
+        unsafe {
+            let next_link = match prev_node {
+                Some(temp_val) => &mut (*temp_val.as_ptr()).next,
+                None => &mut self.head,
+            };
+            let node_to_remove = next_link.unwrap_unchecked().as_ptr();
+
+            // link around the node to remove
+            *next_link = (*node_to_remove).next;
+            if next_link.is_none() {
+                // we removed the tail, so the node before it is the new tail
+                self.tail = prev_node;
+            }
+            self.length -= 1;
+
+            // free the node and move its value out
+            self.free_node(NonNull::new_unchecked(node_to_remove))
+        }
+    }
+
+    /// Adds a value at the index provided. Places the new value before
+    /// the existing value in the list. Index 0 always succeeds, even on an
+    /// empty list, since it pushes the value to the front. Index `len()`
+    /// appends the value to the end of the list.
+    ///
+    /// # Param
+    /// - `value` - The value to add.
+    /// - `idx` - The index in the list to add the value at.
+    ///
+    /// # Returns
+    /// - `OK(())` if the value could be added, `Err(LinkedListError::IndexOutOfBounds)`
+    ///   if `idx` is greater than `len()`.
+    pub fn add_at(&mut self, value: StoreType, idx: usize) -> Result<(), LinkedListError> {
+        if idx > self.length {
+            // we cannot push here
+            return Err(LinkedListError::IndexOutOfBounds {
+                index: idx,
+                len: self.length,
+            });
+        }
+
+        if idx == 0 {
+            // push front
+            self.push_front(value);
+        } else if idx == self.length {
+            // one past the last value, so push back without walking the list
+            self.push_back(value);
+        } else {
+            // SAFETY: idx - 1 is within the list, so the node before where we want to push exists
+            unsafe {
+                let before_node_ptr = self.get_node_at(idx - 1).unwrap_unchecked().as_ptr();
+                self.link_after(before_node_ptr, value);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Inserts a value at the index provided, shifting the value there and every
+    /// value after it back by one. Index `len()` appends the value to the end.
+    ///
+    /// Prefer this over `add_at`, since its argument order matches `Vec::insert`.
+    ///
+    /// # Params
+    /// - `idx` - The index in the list to insert the value at.
+    /// - `value` - The value to insert.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the value could be inserted, `Err(LinkedListError::IndexOutOfBounds)`
+    ///   if `idx` is greater than `len()`.
+    pub fn insert(&mut self, idx: usize, value: StoreType) -> Result<(), LinkedListError> {
+        self.add_at(value, idx)
+    }
+
+    /// Adds a value right after the first value equal to `target`.
+    ///
+    /// # Params
+    /// - `target` - The value to search for.
+    /// - `value` - The value to add.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the value could be added, `Err(LinkedListError::NotFound)`
+    ///   if no value equals `target`.
+    pub fn insert_after_value(
+        &mut self,
+        target: &StoreType,
+        value: StoreType,
+    ) -> Result<(), LinkedListError>
+    where
+        StoreType: PartialEq,
+    {
+        let mut cur_node = self.head;
+
+        // keep going until we find the target or reach the end of the list
+        while let Some(temp_val) = cur_node {
+            let cur_node_ptr = temp_val.as_ptr();
+
+            // SAFETY: cur_node_ptr is always a valid node of this list
+            unsafe {
+                if (*cur_node_ptr).value == *target {
+                    let at_tail = (*cur_node_ptr).next.is_none();
+                    self.link_after(cur_node_ptr, value);
+                    if at_tail {
+                        // the whole list was already walked
+                        self.debug_assert_invariants();
+                    }
+                    return Ok(());
+                }
+                cur_node = (*cur_node_ptr).next;
+            }
+        }
+
+        Err(LinkedListError::NotFound)
+    }
+
+    /// Gets an element in the linked list at this index, like `get`, but with
+    /// an error describing why it couldn't be found.
+    ///
+    /// # Params
+    /// - `idx` - The index in the list to get the value from.
+    ///
+    /// # Returns
+    /// - `Ok(&StoreType)` if the value could be found, `Err(LinkedListError::IndexOutOfBounds)` otherwise.
+    pub fn try_get(&self, idx: usize) -> Result<&StoreType, LinkedListError> {
+        self.get(idx).ok_or(LinkedListError::IndexOutOfBounds {
+            index: idx,
+            len: self.length,
+        })
+    }
+
+    /// Gets references to the values in the range `[start, end)`, walking the
+    /// list only once.
+    ///
+    /// # Params
+    /// - `start` - The index of the first value in the range.
+    /// - `end` - The index one past the last value in the range.
+    ///
+    /// # Returns
+    /// - `Some(Vec<&StoreType>)` with the values in the range, `None` if `start`
+    ///   is greater than `end` or `end` is greater than `len()`.
+    pub fn get_range(&self, start: usize, end: usize) -> Option<Vec<&StoreType>> {
+        if start > end || end > self.length {
+            return None;
+        }
+
+        Some(self.iter().skip(start).take(end - start).collect())
+    }
+
+    /// Replaces the value at this index.
+    ///
+    /// # Params
+    /// - `idx` - The index in the list of the value to replace.
+    /// - `value` - The new value.
+    ///
+    /// # Returns
+    /// - `Ok(StoreType)` with the old value if it could be replaced,
+    ///   `Err(LinkedListError::IndexOutOfBounds)` otherwise.
+    pub fn set(&mut self, idx: usize, value: StoreType) -> Result<StoreType, LinkedListError> {
+        let len = self.length;
+        match self.get_mut(idx) {
+            Some(old_value) => Ok(std::mem::replace(old_value, value)),
+            None => Err(LinkedListError::IndexOutOfBounds { index: idx, len }),
+        }
+    }
+
+    /// Gets the first value in the list.
+    ///
+    /// # Returns
+    /// - `Some(&StoreType)` if the list has a value, `None` otherwise.
+    pub fn first(&self) -> Option<&StoreType> {
+        // SAFETY: the first node is always valid when it is Some value
+        self.head
+            .map(|first_node| unsafe { &first_node.as_ref().value })
+    }
+
+    /// Gets the last value in the list. Uses the cached tail, so it does not
+    /// traverse the list.
+    ///
+    /// # Returns
+    /// - `Some(&StoreType)` if the list has a value, `None` otherwise.
+    pub fn last(&self) -> Option<&StoreType> {
+        // SAFETY: the tail is always a valid node when it is Some value
+        self.tail
+            .map(|tail_node| unsafe { &tail_node.as_ref().value })
+    }
+
+    /// Gets a mutable reference to the first value in the list.
+    ///
+    /// # Returns
+    /// - `Some(&mut StoreType)` if the list has a value, `None` otherwise.
+    pub fn first_mut(&mut self) -> Option<&mut StoreType> {
+        // SAFETY: the first node is always valid when it is Some value, and
+        // the returned reference borrows self mutably
+        self.head
+            .map(|mut first_node| unsafe { &mut first_node.as_mut().value })
+    }
+
+    /// Gets a mutable reference to the last value in the list. Uses the cached
+    /// tail, so it does not traverse the list.
+    ///
+    /// # Returns
+    /// - `Some(&mut StoreType)` if the list has a value, `None` otherwise.
+    pub fn last_mut(&mut self) -> Option<&mut StoreType> {
+        // SAFETY: the tail is always a valid node when it is Some value, and
+        // the returned reference borrows self mutably
+        self.tail
+            .map(|mut tail_node| unsafe { &mut tail_node.as_mut().value })
+    }
+
+    /// Gets a value counting from the back of the list, so offset 0 is the
+    /// last value. Walks the list once, with a runner that starts `offset`
+    /// nodes ahead, so the trailing node is on the value once the runner hits the tail.
+    ///
+    /// # Params
+    /// - `offset` - How many values before the last one to get.
+    ///
+    /// # Returns
+    /// - `Some(&StoreType)` if the value could be found, `None` if `offset` is
+    ///   not less than `len()`.
+    pub fn get_from_end(&self, offset: usize) -> Option<&StoreType> {
+        let mut trailing_node = self.head?;
+        let mut runner_node = trailing_node;
+
+        // SAFETY: both nodes are always valid, and the trailing node is always
+        // offset nodes behind the runner, so it has a next node whenever the runner does
+        unsafe {
+            for _ in 0..offset {
+                runner_node = (*runner_node.as_ptr()).next?;
+            }
+
+            while let Some(next_node) = (*runner_node.as_ptr()).next {
+                runner_node = next_node;
+                trailing_node = (*trailing_node.as_ptr()).next.unwrap_unchecked();
+            }
+
+            Some(&(*trailing_node.as_ptr()).value)
+        }
+    }
+
+    /// Gets the value that `pop_front` would remove, without removing it.
+    ///
+    /// # Returns
+    /// - `Some(&StoreType)` if the list has a value, `None` otherwise.
+    pub fn peek_front(&self) -> Option<&StoreType> {
+        self.first()
+    }
+
+    /// Gets the value that `pop_back` would remove, without removing it.
+    ///
+    /// # Returns
+    /// - `Some(&StoreType)` if the list has a value, `None` otherwise.
+    pub fn peek_back(&self) -> Option<&StoreType> {
+        self.last()
+    }
+
+    /// Gets a mutable reference to an element in the linked list at this index.
+    ///
+    /// # Params
+    /// - `idx` - The index in the list to get the value from.
+    ///
+    /// # Returns
+    /// - `Some(&mut StoreType)` if the value could be found, `None` otherwise.
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut StoreType> {
+        let node = self.get_node_at(idx);
+
+        match node {
+            Some(mut temp_val) => {
+                // SAFETY: temp_val is always valid, and the returned reference
+                // borrows self mutably so nothing else can reach this node
+                unsafe { Some(&mut temp_val.as_mut().value) }
+            }
+            None => None,
+        }
+    }
+
+    /// Gets a mutable reference to the value at this index, or if the index is
+    /// one past the end of the list, pushes the value made by `f` and gets that.
+    ///
+    /// # Params
+    /// - `idx` - The index in the list to get the value from.
+    /// - `f` - Makes the value to push back, only called if `idx` is `len()`.
+    ///
+    /// # Returns
+    /// - A mutable reference to the value at `idx`.
+    ///
+    /// # Panics
+    /// - If `idx` is greater than `len()`.
+    pub fn get_or_insert_with<F: FnOnce() -> StoreType>(
+        &mut self,
+        idx: usize,
+        f: F,
+    ) -> &mut StoreType {
+        let len = self.length;
+        if idx > len {
+            panic!("{}", LinkedListError::IndexOutOfBounds { index: idx, len });
+        } else if idx == len {
+            // the new value is the tail, so there's no need to walk the list
+            self.push_back(f());
+            // SAFETY: a value was just pushed, so the list is not empty
+            return unsafe { self.last_mut().unwrap_unchecked() };
+        }
+
+        // SAFETY: idx is less than the length, so the value exists
+        unsafe { self.get_mut(idx).unwrap_unchecked() }
+    }
+
+    /// Gets mutable references to the values at several indices at once.
+    ///
+    /// # Params
+    /// - `indices` - The indices to get the values from. Must all be different.
+    ///
+    /// # Returns
+    /// - `Some` array of mutable references, in the same order as `indices`.
+    /// - `None` if any index is not less than `len()`, or an index is repeated,
+    ///   since the same value can't be borrowed mutably twice.
+    pub fn get_many_mut<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> Option<[&mut StoreType; N]> {
+        for (position, &idx) in indices.iter().enumerate() {
+            if idx >= self.length || indices[..position].contains(&idx) {
+                return None;
+            }
+        }
+
+        // SAFETY: every index is within the list, so each node exists
+        let nodes = indices.map(|idx| unsafe { self.get_node_at(idx).unwrap_unchecked() });
+
+        // SAFETY: the indices are all different, so every reference points at a
+        // different node, and they all borrow self mutably
+        Some(nodes.map(|mut temp_val| unsafe { &mut temp_val.as_mut().value }))
+    }
+
+    /// Removes every value from the list, leaving it empty and reusable.
+    pub fn clear(&mut self) {
+        // move every node into another list, dropping it frees them
+        drop(self.split_off(0));
+    }
+
+    /// Finds the index of the first value equal to `target`.
+    ///
+    /// # Params
+    /// - `target` - The value to search for.
+    ///
+    /// # Returns
+    /// - `Some(usize)` with the index of the first match, `None` if there is no match.
+    pub fn index_of(&self, target: &StoreType) -> Option<usize>
+    where
+        StoreType: PartialEq,
+    {
+        self.iter().position(|value| value == target)
+    }
+
+    /// Binary searches a sorted list for a value, like `slice::binary_search`.
+    /// Only O(log n) values are compared, but reaching each midpoint still
+    /// walks the nodes, so the search takes O(n) steps overall. If the list
+    /// isn't sorted the result is meaningless.
+    ///
+    /// # Params
+    /// - `target` - The value to search for.
+    ///
+    /// # Returns
+    /// - `Ok(usize)` with the index of a value equal to `target`, `Err(usize)`
+    ///   with the index `target` could be inserted at to keep the list sorted
+    ///   if there is no match.
+    pub fn binary_search(&self, target: &StoreType) -> Result<usize, usize>
+    where
+        StoreType: Ord,
+    {
+        // the values left to search are the `size` values from low_node onward
+        let mut low_node = self.head;
+        let mut low_idx = 0;
+        let mut size = self.length;
+
+        while size > 0 {
+            let half = size / 2;
+
+            // walk from the start of the range to its midpoint
+            let mut mid_node = low_node;
+            for _ in 0..half {
+                // SAFETY: the range holds size nodes, so the midpoint exists
+                mid_node = unsafe { (*mid_node.unwrap_unchecked().as_ptr()).next };
+            }
+            // SAFETY: the range holds size nodes, so the midpoint exists
+            let mid_node = unsafe { mid_node.unwrap_unchecked() };
+
+            // SAFETY: mid_node is a valid node of this list
+            match unsafe { (*mid_node.as_ptr()).value.cmp(target) } {
+                Ordering::Less => {
+                    // keep searching the values after the midpoint
+                    // SAFETY: mid_node is a valid node of this list
+                    low_node = unsafe { (*mid_node.as_ptr()).next };
+                    low_idx += half + 1;
+                    size -= half + 1;
+                }
+                // keep searching the values before the midpoint
+                Ordering::Greater => size = half,
+                Ordering::Equal => return Ok(low_idx + half),
+            }
+        }
+
+        Err(low_idx)
+    }
+
+    /// Checks if both lists hold the same values the same number of times,
+    /// in any order. Sorts clones of the values, leaving both lists untouched.
+    ///
+    /// # Params
+    /// - `other` - The list to compare with.
+    ///
+    /// # Returns
+    /// - `true` if the lists hold the same values, `false` otherwise.
+    pub fn same_multiset(&self, other: &Self) -> bool
+    where
+        StoreType: Ord + Clone,
+    {
+        if self.length != other.length {
+            return false;
+        }
+
+        let mut values = self.to_vec();
+        let mut other_values = other.to_vec();
+        values.sort_unstable();
+        other_values.sort_unstable();
+        values == other_values
+    }
+
+    /// Finds the index of the last value that the predicate returns `true` for.
+    /// The list can only be walked forward, so every value is checked and the
+    /// most recent match is remembered.
+    ///
+    /// # Params
+    /// - `pred` - The predicate that decides whether a value matches.
+    ///
+    /// # Returns
+    /// - `Some(usize)` with the index of the last match, `None` if there is no match.
+    pub fn rposition<F: FnMut(&StoreType) -> bool>(&self, mut pred: F) -> Option<usize> {
+        let mut last_match = None;
+        for (idx, value) in self.iter().enumerate() {
+            if pred(value) {
+                last_match = Some(idx);
+            }
+        }
+
+        last_match
+    }
+
+    /// Reverses the order of the list in place by relinking its nodes.
+    pub fn reverse(&mut self) {
+        // the current first node ends up as the tail
+        self.tail = self.head;
+
+        let mut prev_node = None;
+        let mut cur_node = self.head;
+        while let Some(temp_val) = cur_node {
+            let cur_node_ptr = temp_val.as_ptr();
+
+            // SAFETY: cur_node_ptr is always valid
+            unsafe {
+                // remember where to go next before pointing this node backwards
+                cur_node = (*cur_node_ptr).next;
+                (*cur_node_ptr).next = prev_node;
+            }
+            prev_node = Some(temp_val);
+        }
+
+        // the old tail is the new first node
+        self.head = prev_node;
+        self.debug_assert_invariants();
+    }
+
+    /// Moves all of the values of another list onto the end of this list.
+    /// Runs in constant time, since the nodes are relinked rather than copied.
+    ///
+    /// # Params
+    /// - `other` - The list to move the values out of.
+    pub fn append(&mut self, mut other: LinkedList<StoreType>) {
+        // take the nodes away from other, so its drop won't free them
+        let Some(other_first) = other.head.take() else {
+            return; // nothing to append
+        };
+
+        if let Some(tail_ptr) = self.tail {
+            // SAFETY: the tail is always a valid node when it is Some value
+            unsafe {
+                (*tail_ptr.as_ptr()).next = Some(other_first);
+            }
+        } else {
+            // empty list, the other list's nodes become the whole list
+            self.head = Some(other_first);
+        }
+
+        self.tail = other.tail.take();
+        self.length += other.length;
+        other.length = 0;
+        self.adopt_arena(&other);
+    }
+
+    /// Moves all of the values of another list onto the front of this list,
+    /// keeping their order. Runs in constant time, since the nodes are relinked
+    /// rather than copied.
+    ///
+    /// # Params
+    /// - `other` - The list to move the values out of.
+    pub fn prepend(&mut self, mut other: LinkedList<StoreType>) {
+        // take the nodes away from other, so its drop won't free them
+        let Some(other_tail) = other.tail.take() else {
+            return; // nothing to prepend
+        };
+
+        // SAFETY: the tail is always a valid node when it is Some value
+        unsafe {
+            (*other_tail.as_ptr()).next = self.head;
+        }
+        if self.tail.is_none() {
+            // empty list, the other list's nodes become the whole list
+            self.tail = Some(other_tail);
+        }
+
+        self.head = other.head.take();
+        self.length += other.length;
+        other.length = 0;
+        self.adopt_arena(&other);
+    }
+
+    /// Keeps the arena chunks of another list alive for as long as this list,
+    /// so nodes moved out of it stay valid.
+    ///
+    /// # Params
+    /// - `other` - The list the nodes were moved out of.
+    fn adopt_arena(&mut self, other: &LinkedList<StoreType>) {
+        if let Some(other_arena) = &other.arena {
+            self.arena
+                .get_or_insert_with(Arena::retaining)
+                .adopt(other_arena);
+        }
+    }
+
+    /// Splits the list in two at the index provided. This list keeps the
+    /// values before the index, and the rest are moved into a new list.
+    ///
+    /// # Params
+    /// - `idx` - The index of the first value to move into the new list.
+    ///
+    /// # Returns
+    /// - `Ok(LinkedList)` with the values from `idx` onward, `Err(LinkedListError)`
+    ///   if `idx` is past the end of the list.
+    pub fn split_off(&mut self, idx: usize) -> Result<LinkedList<StoreType>, LinkedListError> {
+        if idx > self.length {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index: idx,
+                len: self.length,
+            });
+        }
+
+        // the other list needs to keep the memory of the nodes it gets alive
+        let mut other = self.new_sibling();
+        if idx == self.length {
+            // nothing to move
+            return Ok(other);
+        } else if idx == 0 {
+            // everything moves, leaving this list empty
+            other.head = self.head.take();
+            other.tail = self.tail.take();
+            other.length = std::mem::take(&mut self.length);
+            return Ok(other);
+        }
+
+        other.tail = self.tail;
+
+        // SAFETY: idx - 1 is within the list, so the node exists and has a next node
+        unsafe {
+            // get the node that will become the tail of this list
+            let before_node_ptr = self.get_node_at(idx - 1).unwrap_unchecked().as_ptr();
+
+            other.head = (*before_node_ptr).next.take();
+            self.tail = Some(NonNull::new_unchecked(before_node_ptr));
+        }
+        other.length = self.length - idx;
+        self.length = idx;
+
+        // no invariant check, the walk stops at idx, and into_chunks splits
+        // in a loop so checking both halves every time would be quadratic
+        Ok(other)
+    }
+
+    /// Splits references to the values of the list in two at the index provided,
+    /// without changing the list. The read-only counterpart to `split_off`.
+    ///
+    /// # Params
+    /// - `idx` - The index of the first value in the second half.
+    ///
+    /// # Returns
+    /// - `Some((front, back))` with the values before `idx` and the values from
+    ///   `idx` onward, `None` if `idx` is greater than `len()`.
+    pub fn split_at(&self, idx: usize) -> Option<(Vec<&StoreType>, Vec<&StoreType>)> {
+        if idx > self.length {
+            return None;
+        }
+
+        let mut iter = self.iter();
+        let front = iter.by_ref().take(idx).collect();
+        Some((front, iter.collect()))
+    }
+
+    /// Rebuilds the list out of new nodes, in list order, then frees the old
+    /// ones. The new nodes all come from one buffer in a fresh arena, so they
+    /// sit next to each other in memory, in the same order as the list. That
+    /// also gives back the memory of nodes removed from an old arena. The list
+    /// allocates its later nodes from the new arena as well, like `with_arena`.
+    pub fn compact(&mut self)
+    where
+        StoreType: Clone,
+    {
+        let mut compacted = LinkedList::new();
+        compacted.arena = Some(Arena::with_capacity(self.length));
+        compacted.extend(self.iter().cloned());
+
+        // dropping the old list frees the old nodes
+        *self = compacted;
+    }
+
+    /// Splits the list into consecutive lists of at most `size` values each.
+    /// Only the last list can be shorter than `size`. The nodes are moved, not copied.
+    ///
+    /// # Params
+    /// - `size` - The number of values in each list.
+    ///
+    /// # Returns
+    /// - The lists in order, or an empty `Vec` if this list is empty.
+    ///
+    /// # Panics
+    /// - If `size` is 0.
+    pub fn into_chunks(mut self, size: usize) -> Vec<LinkedList<StoreType>> {
+        assert!(size != 0, "chunk size must not be 0");
+
+        let mut chunks = Vec::with_capacity(self.length.div_ceil(size));
+        while self.length > size {
+            // SAFETY: size is less than the length, so it is a valid index to split at
+            let rest = unsafe { self.split_off(size).unwrap_unchecked() };
+            chunks.push(std::mem::replace(&mut self, rest));
+        }
+
+        if !self.is_empty() {
+            chunks.push(self);
+        }
+
+        chunks
+    }
+
+    /// Shortens the list, keeping the first `len` values and freeing the rest.
+    /// Does nothing if the list already has `len` or fewer values.
+    ///
+    /// # Params
+    /// - `len` - The number of values to keep.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.length {
+            // detach everything past len, dropping it frees those nodes
+            drop(self.split_off(len));
+        }
+    }
+
+    /// Moves every value of the list into a `Vec`, in list order, freeing each
+    /// node as its value is moved out. If anything panics along the way, the
+    /// values that weren't moved out yet are still freed along with the list.
+    ///
+    /// # Returns
+    /// - A `Vec` holding the values of the list.
+    pub fn into_vec(mut self) -> Vec<StoreType> {
+        let mut values = Vec::with_capacity(self.length);
+        while let Some(value) = self.pop_front() {
+            values.push(value);
+        }
+
+        values
+    }
+
+    /// Clones every value of the list into a `Vec`, in list order.
+    ///
+    /// # Returns
+    /// - A `Vec` holding a clone of each value.
+    pub fn to_vec(&self) -> Vec<StoreType>
+    where
+        StoreType: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+
+    /// Keeps only the values that the predicate returns `true` for, removing
+    /// the rest in a single pass over the list.
+    ///
+    /// # Params
+    /// - `f` - The predicate that decides whether a value is kept.
+    pub fn retain<F: FnMut(&StoreType) -> bool>(&mut self, mut f: F) {
+        let mut prev_node = None;
+        let mut cur_node = self.head;
+
+        while let Some(temp_val) = cur_node {
+            // SAFETY: temp_val is always valid, and is the node after prev_node
+            unsafe {
+                // remember where to go next before this node can be freed
+                cur_node = (*temp_val.as_ptr()).next;
+
+                if f(&(*temp_val.as_ptr()).value) {
+                    prev_node = Some(temp_val);
+                } else {
+                    drop(self.unlink_after(prev_node));
+                }
+            }
+        }
+
+        self.debug_assert_invariants();
+    }
+
+    /// Removes every value that the predicate returns `true` for in a single
+    /// pass, and returns them. The rest of the values stay in the list.
+    ///
+    /// # Params
+    /// - `pred` - The predicate that decides whether a value is extracted.
+    ///
+    /// # Returns
+    /// - The extracted values, in the order they were in the list.
+    pub fn extract_if<F: FnMut(&StoreType) -> bool>(&mut self, mut pred: F) -> Vec<StoreType> {
+        let mut extracted = Vec::new();
+        let mut prev_node = None;
+        let mut cur_node = self.head;
+
+        while let Some(temp_val) = cur_node {
+            // SAFETY: temp_val is always valid, and is the node after prev_node
+            unsafe {
+                // remember where to go next before this node can be freed
+                cur_node = (*temp_val.as_ptr()).next;
+
+                if pred(&(*temp_val.as_ptr()).value) {
+                    extracted.push(self.unlink_after(prev_node));
+                } else {
+                    prev_node = Some(temp_val);
+                }
+            }
+        }
+
+        self.debug_assert_invariants();
+        extracted
+    }
+
+    /// Splits the list in two by a predicate, keeping the order of the values in
+    /// both lists. The nodes are relinked into the new lists, not copied.
+    ///
+    /// # Params
+    /// - `pred` - The predicate that decides which list a value goes into.
+    ///
+    /// # Returns
+    /// - `(matches, rest)`, with the values the predicate returned `true` for
+    ///   in `matches`, and the others in `rest`.
+    pub fn partition<F: FnMut(&StoreType) -> bool>(
+        mut self,
+        mut pred: F,
+    ) -> (LinkedList<StoreType>, LinkedList<StoreType>) {
+        // both lists need to keep the memory of the nodes they get alive
+        let mut matches = self.new_sibling();
+        let mut rest = self.new_sibling();
+
+        while let Some(temp_val) = self.head {
+            // SAFETY: temp_val is always valid. It stays in this list until the
+            // predicate returns, so a panicking predicate can't leak it.
+            unsafe {
+                let is_match = pred(&(*temp_val.as_ptr()).value);
+                self.unlink_front_node();
+
+                if is_match {
+                    matches.link_node_back(temp_val);
+                } else {
+                    rest.link_node_back(temp_val);
+                }
+            }
+        }
+
+        (matches, rest)
+    }
+
+    /// Merges two lists into one by taking values from each in turn, starting
+    /// with this list. Once either list runs out, the rest of the other is
+    /// added to the end. The nodes are relinked into the new list, not copied.
+    ///
+    /// # Params
+    /// - `other` - The list to take every second value from.
+    ///
+    /// # Returns
+    /// - A list alternating between the values of both lists.
+    pub fn interleave(mut self, mut other: LinkedList<StoreType>) -> LinkedList<StoreType> {
+        // the new list needs to keep the memory of the nodes of both lists alive
+        let mut interleaved = self.new_sibling();
+        interleaved.adopt_arena(&other);
+
+        while self.head.is_some() && other.head.is_some() {
+            for list in [&mut self, &mut other] {
+                if let Some(node_ptr) = list.unlink_front_node() {
+                    // SAFETY: the node was just detached from a list whose
+                    // arena chunks the new list keeps alive
+                    unsafe { interleaved.link_node_back(node_ptr) };
+                }
+            }
+        }
+
+        // at most one of them has values left
+        interleaved.append(self);
+        interleaved.append(other);
+        interleaved.debug_assert_invariants();
+        interleaved
+    }
+
+    /// Removes consecutive values that are equal, keeping the first of each run.
+    pub fn dedup(&mut self)
+    where
+        StoreType: PartialEq,
+    {
+        self.dedup_by(|prev, cur| prev == cur);
+    }
+
+    /// Removes consecutive values whose keys are equal, keeping the first of
+    /// each run, like `Vec::dedup_by_key`.
+    ///
+    /// # Params
+    /// - `key` - Closure that gets the key of a value to compare.
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&StoreType) -> K>(&mut self, mut key: F) {
+        self.dedup_by(|prev, cur| key(prev) == key(cur));
+    }
+
+    /// Removes consecutive values that a closure says are the same, keeping
+    /// the first of each run.
+    ///
+    /// # Params
+    /// - `same` - Closure that gets the kept value before a value and the
+    ///   value itself, and returns `true` if the value should be removed.
+    fn dedup_by<F: FnMut(&StoreType, &StoreType) -> bool>(&mut self, mut same: F) {
+        let Some(mut prev_node) = self.head else {
+            return; // nothing to remove
+        };
+
+        // SAFETY: prev_node is always a valid node of this list, and the node
+        // after it is checked to exist before comparing
+        unsafe {
+            while let Some(cur_node) = (*prev_node.as_ptr()).next {
+                if same(&(*prev_node.as_ptr()).value, &(*cur_node.as_ptr()).value) {
+                    drop(self.unlink_after(Some(prev_node)));
+                } else {
+                    prev_node = cur_node;
+                }
+            }
+        }
+
+        self.debug_assert_invariants();
+    }
+
+    /// Sorts the list in ascending order. The sort is stable, and relinks the
+    /// nodes with a merge sort rather than moving any values.
+    pub fn sort(&mut self)
+    where
+        StoreType: Ord,
+    {
+        self.sort_by(StoreType::cmp);
+    }
+
+    /// Sorts the list with the given comparison. The sort is stable, and relinks
+    /// the nodes with a merge sort rather than moving any values.
+    ///
+    /// # Params
+    /// - `compare` - Decides the order of two values.
+    pub fn sort_by<F: FnMut(&StoreType, &StoreType) -> Ordering>(&mut self, mut compare: F) {
+        self.merge_sort(&mut compare);
+    }
+
+    /// Sorts the list with a merge sort using the given comparison, then fixes up the tail.
+    ///
+    /// # Params
+    /// - `compare` - Decides the order of two values.
+    fn merge_sort<F: FnMut(&StoreType, &StoreType) -> Ordering>(&mut self, compare: &mut F) {
+        // SAFETY: the list holds exactly length nodes
+        unsafe {
+            self.head = Self::merge_sort_nodes(self.head, self.length, compare);
+        }
+
+        // the nodes were relinked, so walk to find the new tail
+        let mut cur_node = self.head;
+        while let Some(temp_val) = cur_node {
+            self.tail = Some(temp_val);
+            // SAFETY: temp_val is always valid
+            cur_node = unsafe { temp_val.as_ref().next };
+        }
+
+        self.debug_assert_invariants();
+    }
+
+    /// Sorts the chain of `len` nodes starting at `first_node`.
+    ///
+    /// # Returns
+    /// - The first node of the sorted chain, whose last node points to `None`.
+    ///
+    /// # Safety
+    /// `first_node` must start a chain of exactly `len` valid nodes.
+    unsafe fn merge_sort_nodes<F: FnMut(&StoreType, &StoreType) -> Ordering>(
+        first_node: Option<NonNull<Node<StoreType>>>,
+        len: usize,
+        compare: &mut F,
+    ) -> Option<NonNull<Node<StoreType>>> {
+        if len < 2 {
+            return first_node;
+        }
+
+        // SAFETY: the caller guarantees there are len valid nodes to walk through
+        unsafe {
+            // walk to the last node of the left half
+            let mut cur_node_ptr = first_node.unwrap_unchecked().as_ptr();
+            for _ in 1..len / 2 {
+                cur_node_ptr = (*cur_node_ptr).next.unwrap_unchecked().as_ptr();
+            }
+
+            // cut the chain in two
+            let right = (*cur_node_ptr).next.take();
+
+            let left = Self::merge_sort_nodes(first_node, len / 2, compare);
+            let right = Self::merge_sort_nodes(right, len - len / 2, compare);
+            Self::merge_nodes(left, right, compare)
+        }
+    }
+
+    /// Merges two sorted chains of nodes into one. When values compare equal the
+    /// node from `left` goes first, keeping the sort stable.
+    ///
+    /// # Returns
+    /// - The first node of the merged chain.
+    ///
+    /// # Safety
+    /// `left` and `right` must each start a valid chain of nodes that ends in `None`.
+    unsafe fn merge_nodes<F: FnMut(&StoreType, &StoreType) -> Ordering>(
+        mut left: Option<NonNull<Node<StoreType>>>,
+        mut right: Option<NonNull<Node<StoreType>>>,
+        compare: &mut F,
+    ) -> Option<NonNull<Node<StoreType>>> {
+        let mut first_node = None;
+        let mut last_node: Option<NonNull<Node<StoreType>>> = None;
+
+        // SAFETY: the caller guarantees both chains are valid
+        unsafe {
+            while let (Some(left_node), Some(right_node)) = (left, right) {
+                let left_value = &left_node.as_ref().value;
+                let right_value = &right_node.as_ref().value;
+
+                // take the smaller node off the front of its chain
+                let next_node = if compare(left_value, right_value) == Ordering::Greater {
+                    right = right_node.as_ref().next;
+                    right_node
+                } else {
+                    left = left_node.as_ref().next;
+                    left_node
+                };
+
+                match last_node {
+                    Some(temp_val) => (*temp_val.as_ptr()).next = Some(next_node),
+                    None => first_node = Some(next_node),
+                }
+                last_node = Some(next_node);
+            }
+
+            // one chain ran out, so the rest of the other one is already in order
+            let rest = left.or(right);
+            match last_node {
+                Some(temp_val) => (*temp_val.as_ptr()).next = rest,
+                None => first_node = rest,
+            }
+        }
+
+        first_node
+    }
+
+    /// Counts the values that the predicate returns `true` for.
+    ///
+    /// # Params
+    /// - `pred` - The predicate that decides whether a value is counted.
+    ///
+    /// # Returns
+    /// - The number of matching values.
+    pub fn count_by<F: FnMut(&StoreType) -> bool>(&self, mut pred: F) -> usize {
+        self.iter().filter(|value| pred(value)).count()
+    }
+
+    /// Finds the first value that the predicate returns `true` for.
+    ///
+    /// # Params
+    /// - `pred` - The predicate that decides whether a value matches.
+    ///
+    /// # Returns
+    /// - `Some(&StoreType)` with the first match, `None` if there is no match.
+    pub fn find<F: FnMut(&StoreType) -> bool>(&self, mut pred: F) -> Option<&StoreType> {
+        self.iter().find(|value| pred(value))
+    }
+
+    /// Calls the closure on each value until it returns `Some`, and returns that result.
+    ///
+    /// # Params
+    /// - `f` - Turns a value into `Some` result, or `None` to keep searching.
+    ///
+    /// # Returns
+    /// - The first `Some` result, `None` if the closure never returned one.
+    pub fn find_map<U, F: FnMut(&StoreType) -> Option<U>>(&self, f: F) -> Option<U> {
+        self.iter().find_map(f)
+    }
+
+    /// Checks if the predicate returns `true` for any value. Stops at the first
+    /// value it returns `true` for.
+    ///
+    /// # Params
+    /// - `pred` - The predicate to check each value with.
+    ///
+    /// # Returns
+    /// - `true` if any value matches, `false` otherwise, including for an empty list.
+    pub fn any<F: FnMut(&StoreType) -> bool>(&self, pred: F) -> bool {
+        self.iter().any(pred)
+    }
+
+    /// Checks if the predicate returns `true` for every value. Stops at the first
+    /// value it returns `false` for.
+    ///
+    /// # Params
+    /// - `pred` - The predicate to check each value with.
+    ///
+    /// # Returns
+    /// - `true` if every value matches, including for an empty list, `false` otherwise.
+    pub fn all<F: FnMut(&StoreType) -> bool>(&self, pred: F) -> bool {
+        self.iter().all(pred)
+    }
+
+    /// Finds the largest value in a single pass. Named so that it is not
+    /// shadowed by `Ord::max` when called on an owned list.
+    ///
+    /// # Returns
+    /// - `Some` reference to the largest value, `None` if the list is empty.
+    pub fn max_value(&self) -> Option<&StoreType>
+    where
+        StoreType: Ord,
+    {
+        self.iter().max()
+    }
+
+    /// Finds the smallest value in a single pass. Named so that it is not
+    /// shadowed by `Ord::min` when called on an owned list.
+    ///
+    /// # Returns
+    /// - `Some` reference to the smallest value, `None` if the list is empty.
+    pub fn min_value(&self) -> Option<&StoreType>
+    where
+        StoreType: Ord,
+    {
+        self.iter().min()
+    }
+
+    /// Combines every value into a single result, from the front to the back of the list.
+    ///
+    /// # Params
+    /// - `init` - The starting value of the result.
+    /// - `f` - Combines the result so far with the next value.
+    ///
+    /// # Returns
+    /// - The final result, which is `init` for an empty list.
+    pub fn fold<B, F: FnMut(B, &StoreType) -> B>(&self, init: B, f: F) -> B {
+        self.iter().fold(init, f)
+    }
+
+    /// Calls the closure on every value, from the front to the back of the list.
+    ///
+    /// # Params
+    /// - `f` - The closure to call with each value.
+    pub fn for_each<F: FnMut(&StoreType)>(&self, f: F) {
+        self.iter().for_each(f);
+    }
+
+    /// Builds a new list by calling the closure on every value of this list.
+    ///
+    /// # Params
+    /// - `f` - Turns a value of this list into a value of the new list.
+    ///
+    /// # Returns
+    /// - A new list holding the results, in the same order.
+    pub fn map<U, F: FnMut(&StoreType) -> U>(&self, f: F) -> LinkedList<U> {
+        self.iter().map(f).collect()
+    }
+
+    /// Builds a new list by calling the closure on the values of both lists,
+    /// pair by pair. Stops at the end of the shorter list.
+    ///
+    /// # Params
+    /// - `other` - The list whose values are paired with the values of this list.
+    /// - `f` - Combines a value of this list with the value of `other` at the same index.
+    ///
+    /// # Returns
+    /// - A new list holding the results, as long as the shorter of the two lists.
+    pub fn zip_with<U, V, F: FnMut(&StoreType, &U) -> V>(
+        &self,
+        other: &LinkedList<U>,
+        mut f: F,
+    ) -> LinkedList<V> {
+        self.iter()
+            .zip(other.iter())
+            .map(|(value, other_value)| f(value, other_value))
+            .collect()
+    }
+
+    /// Builds a new list from clones of the values that the predicate returns
+    /// `true` for. This list is left untouched.
+    ///
+    /// # Params
+    /// - `pred` - The predicate that decides whether a value is kept.
+    ///
+    /// # Returns
+    /// - A new list holding the matching values, in the same order.
+    pub fn filter<F: FnMut(&StoreType) -> bool>(&self, mut pred: F) -> LinkedList<StoreType>
+    where
+        StoreType: Clone,
+    {
+        self.iter().filter(|value| pred(value)).cloned().collect()
+    }
+
+    /// Gets an iterator over every pair of neighbouring values in the list.
+    ///
+    /// # Returns
+    /// - An iterator that yields `(a, b)` for each value `a` followed by `b`,
+    ///   so a list of `n` values yields `n - 1` pairs, and a list with fewer
+    ///   than two values yields none.
+    pub fn pairs(&self) -> impl Iterator<Item = (&StoreType, &StoreType)> {
+        self.iter().zip(self.iter().skip(1))
+    }
+
+    /// Gets an iterator over mutable references to the values in the list.
+    ///
+    /// # Returns
+    /// - An `IterMut` that yields the values from the front to the back of the list.
+    pub fn iter_mut(&mut self) -> IterMut<'_, StoreType> {
+        IterMut {
+            next: self.head,
+            marker: PhantomData,
+        }
+    }
+
+    /// Gets a cursor that starts at the first value of the list, and can
+    /// insert and remove values as it moves through the list.
+    ///
+    /// # Returns
+    /// - A `CursorMut` pointing at the first value, or at nothing if the list is empty.
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, StoreType> {
+        CursorMut {
+            current: self.head,
+            prev: None,
+            list: self,
+        }
+    }
+}
+
+impl<StoreType> LinkedList<LinkedList<StoreType>> {
+    /// Joins every inner list into a single list, in order. The nodes of the
+    /// inner lists are relinked with `append`, so no value is moved or copied.
+    ///
+    /// # Returns
+    /// - A list holding the values of every inner list, from the first to the last.
+    pub fn flatten(self) -> LinkedList<StoreType> {
+        let mut flat_list = LinkedList::new();
+        for inner_list in self {
+            flat_list.append(inner_list);
+        }
+
+        flat_list
+    }
+}
+
+// SAFETY: the list uniquely owns all of its nodes the same way a `Box` owns its
+// value, and the raw pointers are never shared outside of it.
+unsafe impl<StoreType: Send> Send for LinkedList<StoreType> {}
+// SAFETY: a shared list only hands out shared references to its values.
+unsafe impl<StoreType: Sync> Sync for LinkedList<StoreType> {}
+
+impl<StoreType> Default for LinkedList<StoreType> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<StoreType: Clone> Clone for LinkedList<StoreType> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        let mut source_iter = source.iter();
+
+        // reuse the nodes we already have by overwriting their values
+        for (value, source_value) in self.iter_mut().zip(&mut source_iter) {
+            value.clone_from(source_value);
+        }
+
+        // then drop any extra nodes, or push whatever the source has left
+        self.truncate(source.length);
+        self.extend(source_iter.cloned());
+    }
+}
+
+impl<StoreType: fmt::Debug> fmt::Debug for LinkedList<StoreType> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Writes the values separated by arrows, like `1 -> 2 -> 3`, or `<empty>` for an empty list.
+impl<StoreType: fmt::Display> fmt::Display for LinkedList<StoreType> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut iter = self.iter();
+        let Some(first) = iter.next() else {
+            return f.write_str("<empty>");
+        };
+
+        write!(f, "{first}")?;
+        for value in iter {
+            write!(f, " -> {value}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<StoreType: PartialEq> PartialEq for LinkedList<StoreType> {
+    fn eq(&self, other: &Self) -> bool {
+        // stops at the first mismatch, or when only one of the lists runs out
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<StoreType: Eq> Eq for LinkedList<StoreType> {}
+
+impl<StoreType: PartialEq> PartialEq<[StoreType]> for LinkedList<StoreType> {
+    fn eq(&self, other: &[StoreType]) -> bool {
+        // the length is cached, so mismatched lengths don't walk the list
+        self.length == other.len() && self.iter().eq(other)
+    }
+}
+
+impl<StoreType: PartialEq> PartialEq<Vec<StoreType>> for LinkedList<StoreType> {
+    fn eq(&self, other: &Vec<StoreType>) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<StoreType: PartialOrd> PartialOrd for LinkedList<StoreType> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        // lexicographic, so a list that is a prefix of the other is less
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<StoreType: Ord> Ord for LinkedList<StoreType> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<StoreType: Hash> Hash for LinkedList<StoreType> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // hash the length too, so lists of lists can't collide by moving values around
+        state.write_usize(self.length);
+        for value in self.iter() {
+            value.hash(state);
+        }
+    }
+}
+
+impl<StoreType> FromIterator<StoreType> for LinkedList<StoreType> {
+    fn from_iter<I: IntoIterator<Item = StoreType>>(iter: I) -> Self {
+        let mut list = LinkedList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<StoreType> Extend<StoreType> for LinkedList<StoreType> {
+    fn extend<I: IntoIterator<Item = StoreType>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+impl<StoreType> Index<usize> for LinkedList<StoreType> {
+    type Output = StoreType;
+
+    fn index(&self, idx: usize) -> &Self::Output {
+        let len = self.length;
+        match self.get(idx) {
+            Some(value) => value,
+            None => panic!("{}", LinkedListError::IndexOutOfBounds { index: idx, len }),
+        }
+    }
+}
+
+impl<StoreType> IndexMut<usize> for LinkedList<StoreType> {
+    fn index_mut(&mut self, idx: usize) -> &mut Self::Output {
+        let len = self.length;
+        match self.get_mut(idx) {
+            Some(value) => value,
+            None => panic!("{}", LinkedListError::IndexOutOfBounds { index: idx, len }),
+        }
+    }
+}
+
+impl<StoreType> From<Vec<StoreType>> for LinkedList<StoreType> {
+    fn from(values: Vec<StoreType>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<StoreType, const N: usize> From<[StoreType; N]> for LinkedList<StoreType> {
+    fn from(values: [StoreType; N]) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<StoreType> From<LinkedList<StoreType>> for Vec<StoreType> {
+    fn from(list: LinkedList<StoreType>) -> Self {
+        list.into_vec()
+    }
+}
+
+/// Iterator over references to the values of a `LinkedList`.
+///
+/// It can also be walked from the back, like with `rev()`. Since the list is
+/// singly linked, the first call to `next_back` walks the values that are
+/// left once and buffers a pointer to each of their nodes, which takes
+/// `O(n)` time and memory. Every call after that takes `O(1)`.
+pub struct Iter<'a, StoreType> {
+    next: Option<NonNull<Node<StoreType>>>,
+    // number of values not yielded yet from either end
+    remaining: usize,
+    // nodes of the values left when next_back was first called, in list order.
+    // The back end pops from here, and remaining keeps it from reaching
+    // values the front end already yielded.
+    back_nodes: Option<Vec<NonNull<Node<StoreType>>>>,
+    // ties the iterator to the lifetime of the borrowed list
+    marker: PhantomData<&'a StoreType>,
+}
+
+// SAFETY: Iter behaves like a `&StoreType` borrowed from the list
+unsafe impl<StoreType: Sync> Send for Iter<'_, StoreType> {}
+// SAFETY: Iter behaves like a `&StoreType` borrowed from the list
+unsafe impl<StoreType: Sync> Sync for Iter<'_, StoreType> {}
+
+impl<'a, StoreType> Iterator for Iter<'a, StoreType> {
+    type Item = &'a StoreType;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None; // the back end may have yielded the rest already
+        }
+        let cur_node = self.next?;
+        self.remaining -= 1;
+
+        // SAFETY: cur_node is always valid while the list is borrowed
+        unsafe {
+            let cur_node = cur_node.as_ref();
+            self.next = cur_node.next;
+            Some(&cur_node.value)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<StoreType> ExactSizeIterator for Iter<'_, StoreType> {}
+
+impl<StoreType> DoubleEndedIterator for Iter<'_, StoreType> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let back_nodes = self.back_nodes.get_or_insert_with(|| {
+            let mut back_nodes = Vec::with_capacity(self.remaining);
+            let mut cur_node = self.next;
+            // a view may end before the list does, so stop after the values that are left
+            while let Some(temp_val) = cur_node.filter(|_| back_nodes.len() < self.remaining) {
+                back_nodes.push(temp_val);
+                // SAFETY: temp_val is always valid while the list is borrowed
+                cur_node = unsafe { temp_val.as_ref().next };
+            }
+            back_nodes
+        });
+
+        // SAFETY: remaining is not 0, so there is a buffered node the front
+        // end hasn't reached, and it is valid while the list is borrowed
+        unsafe {
+            let cur_node = back_nodes.pop().unwrap_unchecked();
+            self.remaining -= 1;
+            Some(&cur_node.as_ref().value)
+        }
+    }
+}
+
+/// Read-only view over a run of values of a `LinkedList`, like the values
+/// left over by `split_first` or `split_last`.
+pub struct RestView<'a, StoreType> {
+    // node of the first value in the view
+    first: Option<NonNull<Node<StoreType>>>,
+    // number of values in the view, the nodes after them are not part of it
+    length: usize,
+    // ties the view to the lifetime of the borrowed list
+    marker: PhantomData<&'a StoreType>,
+}
+
+impl<'a, StoreType> RestView<'a, StoreType> {
+    /// Gets the value at this index of the view. Index 0 is the first value of the view.
+    ///
+    /// # Params
+    /// - `idx` - The index in the view to get the value from.
+    ///
+    /// # Returns
+    /// - `Some(&StoreType)` if the value could be found, `None` otherwise.
+    pub fn get(&self, idx: usize) -> Option<&'a StoreType> {
+        self.iter().nth(idx)
+    }
+
+    /// Gets an iterator over references to the values in the view.
+    ///
+    /// # Returns
+    /// - An `Iter` that yields the values from the front to the back of the view.
+    pub fn iter(&self) -> Iter<'a, StoreType> {
+        Iter {
+            next: self.first,
+            remaining: self.length,
+            back_nodes: None,
+            marker: PhantomData,
+        }
+    }
+
+    /// Gets the number of values in the view.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Checks if the view has no values.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+// SAFETY: RestView behaves like a `&StoreType` borrowed from the list
+unsafe impl<StoreType: Sync> Send for RestView<'_, StoreType> {}
+// SAFETY: RestView behaves like a `&StoreType` borrowed from the list
+unsafe impl<StoreType: Sync> Sync for RestView<'_, StoreType> {}
+
+/// Iterator over mutable references to the values of a `LinkedList`.
+pub struct IterMut<'a, StoreType> {
+    next: Option<NonNull<Node<StoreType>>>,
+    // ties the iterator to the lifetime of the mutably borrowed list
+    marker: PhantomData<&'a mut StoreType>,
+}
+
+// SAFETY: IterMut behaves like a `&mut StoreType` borrowed from the list
+unsafe impl<StoreType: Send> Send for IterMut<'_, StoreType> {}
+// SAFETY: IterMut behaves like a `&mut StoreType` borrowed from the list
+unsafe impl<StoreType: Sync> Sync for IterMut<'_, StoreType> {}
+
+impl<'a, StoreType> Iterator for IterMut<'a, StoreType> {
+    type Item = &'a mut StoreType;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut cur_node = self.next?;
+
+        // SAFETY: cur_node is always valid while the list is borrowed. We move
+        // past the node before handing out its value, so every node is only
+        // ever yielded once and no two mutable references alias.
+        unsafe {
+            let cur_node = cur_node.as_mut();
+            self.next = cur_node.next;
+            Some(&mut cur_node.value)
+        }
+    }
+}
+
+/// Iterator that consumes a `LinkedList` and yields its values.
+///
+/// Any values that were not yielded are freed along with the wrapped list
+/// when the iterator is dropped.
+pub struct IntoIter<StoreType> {
+    list: LinkedList<StoreType>,
+}
+
+impl<StoreType> Iterator for IntoIter<StoreType> {
+    type Item = StoreType;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.length, Some(self.list.length))
+    }
+}
+
+impl<StoreType> ExactSizeIterator for IntoIter<StoreType> {}
+
+impl<StoreType> IntoIterator for LinkedList<StoreType> {
+    type Item = StoreType;
+    type IntoIter = IntoIter<StoreType>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { list: self }
+    }
+}
+
+/// Cursor over a `LinkedList` that can edit the list at its position.
+///
+/// The cursor either points at a value, or at nothing once it moves past the
+/// end of the list. Moving forward from nothing wraps back to the first value.
+pub struct CursorMut<'a, StoreType> {
+    // node the cursor points at, None when past the end of the list
+    current: Option<NonNull<Node<StoreType>>>,
+    // node before current, None when current is the first node.
+    // Needed to unlink current since the list is singly linked.
+    prev: Option<NonNull<Node<StoreType>>>,
+    list: &'a mut LinkedList<StoreType>,
+}
+
+impl<StoreType> CursorMut<'_, StoreType> {
+    /// Moves the cursor to the next value. Moving past the last value leaves
+    /// the cursor pointing at nothing, and moving again goes back to the first value.
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(temp_val) => {
+                self.prev = Some(temp_val);
+                // SAFETY: temp_val is always valid while the list is borrowed
+                self.current = unsafe { temp_val.as_ref().next };
+            }
+            None => {
+                // wrap around to the front of the list
+                self.prev = None;
+                self.current = self.list.head;
+            }
+        }
+    }
+
+    /// Gets the value the cursor points at.
+    ///
+    /// # Returns
+    /// - `Some(&StoreType)` if the cursor points at a value, `None` otherwise.
+    pub fn peek(&self) -> Option<&StoreType> {
+        // SAFETY: current is always valid while the list is borrowed
+        self.current
+            .map(|temp_val| unsafe { &temp_val.as_ref().value })
+    }
+
+    /// Inserts a value after the one the cursor points at. If the cursor points
+    /// at nothing, the value is inserted at the front of the list.
+    /// The cursor does not move.
+    ///
+    /// # Params
+    /// - `value` - The value to insert.
+    pub fn insert_after(&mut self, value: StoreType) {
+        let Some(temp_val) = self.current else {
+            // pointing at nothing, so the front of the list comes next
+            self.list.push_front(value);
+            return;
+        };
+
+        // SAFETY: current is always a valid node of the borrowed list
+        unsafe {
+            self.list.link_after(temp_val.as_ptr(), value);
+        }
+    }
+
+    /// Removes the value the cursor points at, moving the cursor to the next value.
+    ///
+    /// # Returns
+    /// - `Some(StoreType)` with the removed value, `None` if the cursor points at nothing.
+    pub fn remove_current(&mut self) -> Option<StoreType> {
+        let temp_val = self.current?;
+
+        // SAFETY: current is always valid, and comes right after prev in the list
+        unsafe {
+            self.current = temp_val.as_ref().next;
+            Some(self.list.unlink_after(self.prev))
+        }
+    }
+}
+
+/// Serializes a `LinkedList` as a sequence of its values, in list order.
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::LinkedList;
+    use serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
+    use serde::ser::{Serialize, Serializer};
+    use std::fmt;
+    use std::marker::PhantomData;
+
+    impl<StoreType: Serialize> Serialize for LinkedList<StoreType> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(self.iter())
+        }
+    }
+
+    /// Visitor that rebuilds a list by pushing each value of the sequence to the back.
+    struct LinkedListVisitor<StoreType> {
+        marker: PhantomData<StoreType>,
+    }
+
+    impl<'de, StoreType: Deserialize<'de>> Visitor<'de> for LinkedListVisitor<StoreType> {
+        type Value = LinkedList<StoreType>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a sequence")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut list = LinkedList::new();
+            while let Some(value) = seq.next_element()? {
+                list.push_back(value);
+            }
+            Ok(list)
+        }
+    }
+
+    impl<'de, StoreType: Deserialize<'de>> Deserialize<'de> for LinkedList<StoreType> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_seq(LinkedListVisitor {
+                marker: PhantomData,
+            })
+        }
+    }
+}
+
+impl<StoreType> Drop for LinkedList<StoreType> {
+    fn drop(&mut self) {
+        /// Frees the rest of the list if dropping one of its values panics.
+        struct DropGuard<'a, StoreType>(&'a mut LinkedList<StoreType>);
+
+        impl<StoreType> Drop for DropGuard<'_, StoreType> {
+            fn drop(&mut self) {
+                while let Some(value) = self.0.pop_front() {
+                    drop(value);
+                }
+                self.0.drain_pool();
+            }
+        }
+
+        // free the nodes one at a time rather than recursing through them,
+        // so long lists can't overflow the stack
+        while let Some(value) = self.pop_front() {
+            let guard = DropGuard(self);
+            drop(value);
+            std::mem::forget(guard);
+        }
+        self.drain_pool();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::collections::{BTreeSet, HashSet};
+    use std::rc::Rc;
+
+    thread_local! {
+        /// Number of times debug_assert_invariants() ran on this test's thread.
+        pub(super) static INVARIANT_CHECKS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    #[test]
+    fn test_small_list_operations() {
+        let mut list = LinkedList::<i32>::new();
+
+        list.push_front(4);
+        assert_eq!(4, *list.get(0).unwrap());
+
+        list.push_front(5);
+        assert_eq!(5, *list.get(0).unwrap());
+        assert_eq!(4, *list.get(1).unwrap());
+
+        list.remove(0)
+            .expect("Removing this element should not cause an error");
+        assert_eq!(4, *list.get(0).unwrap());
+
+        list.push_back(100);
+        assert_eq!(4, *list.get(0).unwrap());
+        assert_eq!(100, *list.get(1).unwrap());
+    }
+
+    #[test]
+    fn test_larger_push_back() {
+        let mut list = LinkedList::<i32>::new();
+
+        list.push_back(100);
+        list.push_back(101);
+        list.push_back(102);
+        list.push_back(103);
+        list.push_back(104);
+        assert_eq!(100, *list.get(0).unwrap());
+        assert_eq!(101, *list.get(1).unwrap());
+        assert_eq!(102, *list.get(2).unwrap());
+        assert_eq!(103, *list.get(3).unwrap());
+        assert_eq!(104, *list.get(4).unwrap());
+    }
+
+    #[test]
+    fn test_add_at() {
+        let mut list = LinkedList::<i32>::new();
+
+        list.add_at(1, 0).unwrap();
+        list.add_at(3, 1).unwrap();
+        list.add_at(4, 2).unwrap();
+        list.add_at(0, 0).unwrap();
+        list.add_at(2, 2).unwrap();
+
+        assert_eq!(0, *list.get(0).unwrap());
+        assert_eq!(1, *list.get(1).unwrap());
+        assert_eq!(2, *list.get(2).unwrap());
+        assert_eq!(3, *list.get(3).unwrap());
+        assert_eq!(4, *list.get(4).unwrap());
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut list = LinkedList::<i32>::new();
+
+        list.push_back(0);
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        list.push_back(4);
+
+        list.remove(0).unwrap();
+        list.remove(2).unwrap();
+        list.remove(2).unwrap();
+        assert_eq!(
+            Err(LinkedListError::IndexOutOfBounds { index: 2, len: 2 }),
+            list.remove(2)
+        );
+
+        assert_eq!(1, *list.get(0).unwrap());
+        assert_eq!(2, *list.get(1).unwrap());
+    }
+
+    #[test]
+    fn test_push_front() {
+        let mut list = LinkedList::<i32>::new();
+
+        list.push_front(4);
+        list.push_front(3);
+        list.push_front(2);
+        list.push_front(1);
+        list.push_front(0);
+
+        assert_eq!(0, *list.get(0).unwrap());
+        assert_eq!(1, *list.get(1).unwrap());
+        assert_eq!(2, *list.get(2).unwrap());
+        assert_eq!(3, *list.get(3).unwrap());
+        assert_eq!(4, *list.get(4).unwrap());
+    }
+
+    #[test]
+    fn test_push_back() {
+        let mut list = LinkedList::<i32>::new();
+
+        list.push_back(0);
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        list.push_back(4);
+
+        assert_eq!(0, *list.get(0).unwrap());
+        assert_eq!(1, *list.get(1).unwrap());
+        assert_eq!(2, *list.get(2).unwrap());
+        assert_eq!(3, *list.get(3).unwrap());
+        assert_eq!(4, *list.get(4).unwrap());
+    }
+
+    #[test]
+    fn test_get() {
+        let mut list = LinkedList::<i32>::new();
+
+        let mut result = list.get(0);
+        assert!(result.is_none());
+        list.push_back(0);
+        assert_eq!(0, *list.get(0).unwrap());
+        list.push_back(1);
+        assert_eq!(1, *list.get(1).unwrap());
+        list.push_back(2);
+        assert_eq!(2, *list.get(2).unwrap());
+        list.push_back(3);
+        assert_eq!(3, *list.get(3).unwrap());
+        list.push_back(4);
+        assert_eq!(4, *list.get(4).unwrap());
+        result = list.get(5);
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_len() {
+        let mut list = LinkedList::<i32>::new();
+        assert_eq!(0, list.len());
+
+        list.push_front(1);
+        list.push_front(0);
+        assert_eq!(2, list.len());
+
+        list.push_back(2);
+        list.push_back(3);
+        assert_eq!(4, list.len());
+
+        list.remove(3).unwrap();
+        assert_eq!(3, list.len());
+
+        list.remove(0).unwrap();
+        list.remove(0).unwrap();
+        list.remove(0).unwrap();
+        assert_eq!(0, list.len());
+    }
+
+    #[test]
+    fn test_is_empty() {
+        let mut list = LinkedList::<i32>::new();
+        assert!(list.is_empty());
+
+        list.push_back(0);
+        list.push_front(1);
+        list.push_back(2);
+        assert!(!list.is_empty());
+
+        while !list.is_empty() {
+            list.remove(0).unwrap();
+        }
+        assert!(list.is_empty());
+        assert!(list.get(0).is_none());
+    }
+
+    #[test]
+    fn test_pop_front() {
+        let mut list = LinkedList::<i32>::new();
+        assert_eq!(None, list.pop_front());
+
+        list.push_back(0);
+        list.push_back(1);
+        list.push_back(2);
+
+        assert_eq!(Some(0), list.pop_front());
+        list.push_back(3);
+        assert_eq!(Some(1), list.pop_front());
+        assert_eq!(Some(2), list.pop_front());
+        assert_eq!(Some(3), list.pop_front());
+        assert_eq!(None, list.pop_front());
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_pop_back() {
+        let mut list = LinkedList::<i32>::new();
+        assert_eq!(None, list.pop_back());
+
+        list.push_back(0);
+        assert_eq!(Some(0), list.pop_back());
+        assert!(list.is_empty());
+
+        list.push_back(1);
+        list.push_back(2);
+        assert_eq!(Some(2), list.pop_back());
+        list.push_back(3);
+        list.push_back(4);
+        assert_eq!(Some(4), list.pop_back());
+        assert_eq!(Some(3), list.pop_back());
+        assert_eq!(Some(1), list.pop_back());
+        assert_eq!(None, list.pop_back());
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut list = LinkedList::<i32>::new();
+        assert!(list.get_mut(0).is_none());
+
+        list.push_back(0);
+        list.push_back(1);
+        list.push_back(2);
+
+        *list.get_mut(1).unwrap() += 10;
+        assert_eq!(0, *list.get(0).unwrap());
+        assert_eq!(11, *list.get(1).unwrap());
+        assert_eq!(2, *list.get(2).unwrap());
+        assert!(list.get_mut(3).is_none());
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut list = LinkedList::<i32>::new();
+        assert!(list.iter().next().is_none());
+
+        list.push_back(0);
+        list.push_back(1);
+        list.push_back(2);
+
+        let mut iter = list.iter();
+        assert_eq!(Some(&0), iter.next());
+        assert_eq!(Some(&1), iter.next());
+        assert_eq!(Some(&2), iter.next());
+        assert_eq!(None, iter.next());
+        assert_eq!(None, iter.next());
+
+        assert_eq!(vec![&0, &1, &2], list.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut list = LinkedList::<i32>::new();
+        assert!(list.iter_mut().next().is_none());
+
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        for value in list.iter_mut() {
+            *value *= 2;
+        }
+
+        assert_eq!(2, *list.get(0).unwrap());
+        assert_eq!(4, *list.get(1).unwrap());
+        assert_eq!(6, *list.get(2).unwrap());
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let mut list = LinkedList::<i32>::new();
+        list.push_back(0);
+        list.push_back(1);
+        list.push_back(2);
+
+        let mut expected = 0;
+        for value in list {
+            assert_eq!(expected, value);
+            expected += 1;
+        }
+        assert_eq!(3, expected);
+
+        // stopping early must still free the values that were not yielded
+        let tracker = Rc::new(());
+        let mut list = LinkedList::<Rc<()>>::new();
+        for _ in 0..5 {
+            list.push_back(Rc::clone(&tracker));
+        }
+        assert_eq!(6, Rc::strong_count(&tracker));
+
+        for (count, value) in list.into_iter().enumerate() {
+            drop(value);
+            if count == 1 {
+                break;
+            }
+        }
+        assert_eq!(1, Rc::strong_count(&tracker));
+    }
+
+    #[test]
+    fn test_drop_large_list() {
+        let tracker = Rc::new(());
+        let mut list = LinkedList::<Rc<()>>::new();
+        for _ in 0..1000 {
+            list.push_front(Rc::clone(&tracker));
+        }
+        assert_eq!(1001, Rc::strong_count(&tracker));
+
+        drop(list);
+        assert_eq!(1, Rc::strong_count(&tracker));
+    }
+
+    #[test]
+    fn test_drop_panic_frees_the_rest() {
+        /// Value whose drop panics when asked to, after counting the drop.
+        struct PanicOnDrop {
+            drops: Rc<Cell<usize>>,
+            panics: bool,
+        }
+
+        impl Drop for PanicOnDrop {
+            fn drop(&mut self) {
+                self.drops.set(self.drops.get() + 1);
+                if self.panics {
+                    panic!("dropping this value panics");
+                }
+            }
+        }
+
+        let drops = Rc::new(Cell::new(0));
+        let list: LinkedList<_> = (0..5)
+            .map(|idx| PanicOnDrop {
+                drops: Rc::clone(&drops),
+                panics: idx == 1,
+            })
+            .collect();
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| drop(list)));
+        assert!(result.is_err());
+        // the values after the panicking one were still dropped
+        assert_eq!(5, drops.get());
+    }
+
+    #[test]
+    fn test_remove_head_middle_tail() {
+        let mut list = LinkedList::<i32>::new();
+        for value in 0..6 {
+            list.push_back(value);
+        }
+
+        // head
+        list.remove(0).unwrap();
+        // middle, removes the value 3
+        list.remove(2).unwrap();
+        // tail
+        list.remove(3).unwrap();
+
+        assert_eq!(3, list.len());
+        assert_eq!(1, *list.get(0).unwrap());
+        assert_eq!(2, *list.get(1).unwrap());
+        assert_eq!(4, *list.get(2).unwrap());
+        assert!(list.get(3).is_none());
+
+        // the list must still be usable after removing the tail
+        list.push_back(6);
+        assert_eq!(6, *list.get(3).unwrap());
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut list = LinkedList::<i32>::new();
+        list.clear();
+        assert!(list.is_empty());
+
+        for value in 0..500 {
+            list.push_back(value);
+        }
+        list.clear();
+        assert!(list.is_empty());
+        assert!(list.get(0).is_none());
+
+        list.push_back(1);
+        list.push_front(0);
+        assert_eq!(2, list.len());
+        assert_eq!(0, *list.get(0).unwrap());
+        assert_eq!(1, *list.get(1).unwrap());
+    }
+
+    #[test]
+    fn test_debug() {
+        let mut list = LinkedList::<i32>::new();
+        assert_eq!("[]", format!("{:?}", list));
+
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        assert_eq!("[1, 2, 3]", format!("{:?}", list));
+
+        let mut list = LinkedList::<String>::new();
+        list.push_back(String::from("a"));
+        list.push_back(String::from("b"));
+        assert_eq!("[\"a\", \"b\"]", format!("{:?}", list));
+    }
+
+    #[test]
+    fn test_eq() {
+        let mut list_a = LinkedList::<i32>::new();
+        let mut list_b = LinkedList::<i32>::new();
+        assert_eq!(list_a, list_b);
+
+        for value in 0..3 {
+            list_a.push_back(value);
+            list_b.push_back(value);
+        }
+        assert_eq!(list_a, list_b);
+
+        // differ only in length
+        list_b.push_back(3);
+        assert_ne!(list_a, list_b);
+        assert_ne!(list_b, list_a);
+
+        // differ only in a middle value
+        list_b.pop_back();
+        *list_b.get_mut(1).unwrap() = 10;
+        assert_ne!(list_a, list_b);
+    }
+
+    #[test]
+    fn test_linked_list_macro() {
+        let list = linked_list![1, 2, 3];
+        assert_eq!(3, list.len());
+        assert_eq!(1, *list.get(0).unwrap());
+        assert_eq!(2, *list.get(1).unwrap());
+        assert_eq!(3, *list.get(2).unwrap());
+
+        let list: LinkedList<i32> = linked_list![];
+        assert!(list.is_empty());
+        assert!(list.get(0).is_none());
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let list: LinkedList<i32> = (0..5).collect();
+        assert_eq!(linked_list![0, 1, 2, 3, 4], list);
+
+        let list: LinkedList<i32> = vec![3, 1, 2].into_iter().collect();
+        assert_eq!(linked_list![3, 1, 2], list);
+
+        let list: LinkedList<i32> = std::iter::empty().collect();
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_extend() {
+        let mut list = linked_list![1, 2, 3];
+        list.extend(vec![4, 5, 6]);
+        assert_eq!(linked_list![1, 2, 3, 4, 5, 6], list);
+
+        let mut list = LinkedList::<i32>::new();
+        list.extend(0..3);
+        assert_eq!(linked_list![0, 1, 2], list);
+    }
+
+    /// Gets the value stored in the cached tail node.
+    fn tail_value<StoreType>(list: &LinkedList<StoreType>) -> Option<&StoreType> {
+        // SAFETY: the tail is always a valid node when it is Some value
+        list.tail.map(|tail| unsafe { &tail.as_ref().value })
+    }
+
+    #[test]
+    fn test_push_back_many() {
+        let mut list = LinkedList::<i32>::new();
+        for value in 0..100_000 {
+            list.push_back(value);
+        }
+
+        assert_eq!(100_000, list.len());
+        assert_eq!(0, *list.get(0).unwrap());
+        assert_eq!(99_999, *list.get(99_999).unwrap());
+        assert_eq!(Some(&99_999), tail_value(&list));
+    }
+
+    #[test]
+    fn test_tail_after_removals() {
+        let mut list = linked_list![0, 1, 2, 3];
+        assert_eq!(Some(&3), tail_value(&list));
+
+        list.remove(3).unwrap();
+        assert_eq!(Some(&2), tail_value(&list));
+
+        assert_eq!(Some(2), list.pop_back());
+        assert_eq!(Some(&1), tail_value(&list));
+
+        list.add_at(5, 2).unwrap();
+        assert_eq!(Some(&5), tail_value(&list));
+
+        list.remove(0).unwrap();
+        list.remove(0).unwrap();
+        assert_eq!(Some(&5), tail_value(&list));
+        assert_eq!(Some(5), list.pop_front());
+        assert_eq!(None, tail_value(&list));
+
+        list.push_front(7);
+        assert_eq!(Some(&7), tail_value(&list));
+        list.push_back(8);
+        assert_eq!(linked_list![7, 8], list);
+
+        list.clear();
+        assert_eq!(None, tail_value(&list));
+        list.push_back(9);
+        assert_eq!(linked_list![9], list);
+    }
+
+    #[test]
+    fn test_len_matches_traversal() {
+        let mut list = LinkedList::<u32>::new();
+
+        // simple linear congruential generator so the sequence is repeatable
+        let mut seed: u32 = 12345;
+        let mut next_random = move || {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            seed >> 16
+        };
+
+        for _ in 0..2000 {
+            let value = next_random();
+            let idx = next_random() as usize % (list.len() + 2);
+            match value % 6 {
+                0 => list.push_front(value),
+                1 => list.push_back(value),
+                2 => {
+                    let _ = list.add_at(value, idx);
+                }
+                3 => {
+                    let _ = list.remove(idx);
+                }
+                4 => {
+                    list.pop_front();
+                }
+                _ => {
+                    list.pop_back();
+                }
+            }
+            assert_eq!(list.iter().count(), list.len());
+        }
+
+        list.clear();
+        assert_eq!(0, list.len());
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut list = LinkedList::<i32>::new();
+        assert_eq!(Err(LinkedListError::EmptyList), list.remove(0));
+        assert_eq!(Err(LinkedListError::EmptyList), list.remove(3));
+        assert_eq!(
+            Err(LinkedListError::IndexOutOfBounds { index: 1, len: 0 }),
+            list.add_at(0, 1)
+        );
+
+        list.push_back(0);
+        assert_eq!(
+            Err(LinkedListError::IndexOutOfBounds { index: 3, len: 1 }),
+            list.add_at(1, 3)
+        );
+
+        assert_eq!(
+            "index out of bounds: the len is 1 but the index is 3",
+            LinkedListError::IndexOutOfBounds { index: 3, len: 1 }.to_string()
+        );
+        assert_eq!("the list is empty", LinkedListError::EmptyList.to_string());
+    }
+
+    #[test]
+    fn test_index_of() {
+        let list = linked_list![5, 6, 7, 6, 5];
+        assert_eq!(Some(0), list.index_of(&5));
+        assert_eq!(Some(1), list.index_of(&6));
+        assert_eq!(Some(2), list.index_of(&7));
+        assert_eq!(None, list.index_of(&8));
+        assert_eq!(6, *list.get(list.index_of(&6).unwrap()).unwrap());
+
+        let list = LinkedList::<i32>::new();
+        assert_eq!(None, list.index_of(&0));
+    }
+
+    #[test]
+    fn test_reverse() {
+        let mut list = linked_list![1, 2, 3, 4];
+        list.reverse();
+        assert_eq!(linked_list![4, 3, 2, 1], list);
+        assert_eq!(Some(&1), tail_value(&list));
+
+        list.reverse();
+        assert_eq!(linked_list![1, 2, 3, 4], list);
+        list.push_back(5);
+        assert_eq!(linked_list![1, 2, 3, 4, 5], list);
+
+        let mut list = LinkedList::<i32>::new();
+        list.reverse();
+        assert!(list.is_empty());
+        assert_eq!(None, tail_value(&list));
+
+        let mut list = linked_list![1];
+        list.reverse();
+        assert_eq!(linked_list![1], list);
+        assert_eq!(Some(&1), tail_value(&list));
+    }
+
+    #[test]
+    fn test_append() {
+        let mut list = linked_list![1, 2];
+        list.append(linked_list![3, 4]);
+        assert_eq!(linked_list![1, 2, 3, 4], list);
+        assert_eq!(4, list.len());
+        list.push_back(5);
+        assert_eq!(linked_list![1, 2, 3, 4, 5], list);
+
+        list.append(LinkedList::new());
+        assert_eq!(5, list.len());
+
+        let mut list = LinkedList::<i32>::new();
+        list.append(linked_list![1, 2]);
+        assert_eq!(linked_list![1, 2], list);
+        assert_eq!(Some(&2), tail_value(&list));
+
+        // both lists going out of scope must free every value exactly once
+        let tracker = Rc::new(());
+        let mut list_a = LinkedList::<Rc<()>>::new();
+        let mut list_b = LinkedList::<Rc<()>>::new();
+        for _ in 0..3 {
+            list_a.push_back(Rc::clone(&tracker));
+            list_b.push_back(Rc::clone(&tracker));
+        }
+        list_a.append(list_b);
+        assert_eq!(7, Rc::strong_count(&tracker));
+        drop(list_a);
+        assert_eq!(1, Rc::strong_count(&tracker));
+    }
+
+    #[test]
+    fn test_split_off() {
+        let mut list = linked_list![0, 1, 2, 3, 4];
+        let other = list.split_off(2).unwrap();
+        assert_eq!(linked_list![0, 1], list);
+        assert_eq!(linked_list![2, 3, 4], other);
+        assert_eq!(2, list.len());
+        assert_eq!(3, other.len());
+        assert_eq!(Some(&1), tail_value(&list));
+        assert_eq!(Some(&4), tail_value(&other));
+
+        let other = list.split_off(2).unwrap();
+        assert!(other.is_empty());
+        assert_eq!(linked_list![0, 1], list);
+
+        assert_eq!(
+            Err(LinkedListError::IndexOutOfBounds { index: 3, len: 2 }),
+            list.split_off(3)
+        );
+
+        let other = list.split_off(0).unwrap();
+        assert!(list.is_empty());
+        assert_eq!(None, tail_value(&list));
+        assert_eq!(linked_list![0, 1], other);
+    }
+
+    #[test]
+    fn test_truncate() {
+        let mut list = linked_list![0, 1, 2, 3, 4];
+        list.truncate(10);
+        assert_eq!(linked_list![0, 1, 2, 3, 4], list);
+        list.truncate(5);
+        assert_eq!(5, list.len());
+
+        list.truncate(2);
+        assert_eq!(linked_list![0, 1], list);
+        assert_eq!(Some(&1), tail_value(&list));
+        list.push_back(2);
+        assert_eq!(linked_list![0, 1, 2], list);
+
+        list.truncate(0);
+        assert!(list.is_empty());
+        assert_eq!(None, tail_value(&list));
+    }
+
+    #[test]
+    fn test_index() {
+        let mut list = linked_list![0, 1, 2];
+        assert_eq!(0, list[0]);
+        assert_eq!(2, list[2]);
+
+        list[1] = 10;
+        list[2] += 1;
+        assert_eq!(linked_list![0, 10, 3], list);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 3 but the index is 3")]
+    fn test_index_out_of_bounds() {
+        let list = linked_list![0, 1, 2];
+        let _ = list[3];
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 0 but the index is 0")]
+    fn test_index_mut_out_of_bounds() {
+        let mut list = LinkedList::<i32>::new();
+        list[0] = 1;
+    }
+
+    #[test]
+    fn test_first_last() {
+        let mut list = LinkedList::<i32>::new();
+        assert_eq!(None, list.first());
+        assert_eq!(None, list.last());
+
+        list.push_back(1);
+        assert_eq!(Some(&1), list.first());
+        assert_eq!(Some(&1), list.last());
+
+        list.push_back(2);
+        list.push_back(3);
+        assert_eq!(Some(&1), list.first());
+        assert_eq!(Some(&3), list.last());
+    }
+
+    #[test]
+    fn test_from_vec() {
+        let list = LinkedList::from(vec![1, 2, 3]);
+        assert_eq!(linked_list![1, 2, 3], list);
+
+        let list = LinkedList::<i32>::from(Vec::new());
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_to_vec() {
+        let list = linked_list![3, 1, 2];
+        assert_eq!(vec![3, 1, 2], list.to_vec());
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), list.to_vec());
+        assert_eq!(Vec::<i32>::new(), LinkedList::<i32>::new().to_vec());
+
+        let tracker = Rc::new(());
+        let mut list = LinkedList::<Rc<()>>::new();
+        for _ in 0..3 {
+            list.push_back(Rc::clone(&tracker));
+        }
+        let values: Vec<Rc<()>> = list.into();
+        assert_eq!(3, values.len());
+        assert_eq!(4, Rc::strong_count(&tracker));
+        drop(values);
+        assert_eq!(1, Rc::strong_count(&tracker));
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut list = linked_list![1, 2, 3, 4, 5, 6];
+        list.retain(|value| value % 2 == 0);
+        assert_eq!(linked_list![2, 4, 6], list);
+        assert_eq!(3, list.len());
+        assert_eq!(Some(&6), tail_value(&list));
+
+        list.retain(|value| *value < 6);
+        assert_eq!(linked_list![2, 4], list);
+        assert_eq!(Some(&4), tail_value(&list));
+
+        list.retain(|_| false);
+        assert!(list.is_empty());
+        assert_eq!(0, list.len());
+        assert_eq!(None, tail_value(&list));
+    }
+
+    #[test]
+    fn test_cursor_mut() {
+        let mut list = linked_list![1, 2, 4, 5];
+        let mut cursor = list.cursor_front_mut();
+        assert_eq!(Some(&1), cursor.peek());
+
+        cursor.move_next();
+        assert_eq!(Some(&2), cursor.peek());
+        cursor.insert_after(3);
+        assert_eq!(Some(&2), cursor.peek());
+
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(Some(&4), cursor.peek());
+        assert_eq!(Some(4), cursor.remove_current());
+        assert_eq!(Some(&5), cursor.peek());
+
+        // removing the tail moves the cursor past the end
+        assert_eq!(Some(5), cursor.remove_current());
+        assert_eq!(None, cursor.peek());
+        assert_eq!(None, cursor.remove_current());
+
+        // inserting past the end goes to the front
+        cursor.insert_after(0);
+        cursor.move_next();
+        assert_eq!(Some(&0), cursor.peek());
+        assert_eq!(Some(0), cursor.remove_current());
+
+        assert_eq!(linked_list![1, 2, 3], list);
+        assert_eq!(3, list.len());
+        assert_eq!(Some(&3), tail_value(&list));
+
+        // inserting after the tail moves the tail
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        cursor.move_next();
+        cursor.insert_after(4);
+        assert_eq!(Some(&4), tail_value(&list));
+        list.push_back(5);
+        assert_eq!(linked_list![1, 2, 3, 4, 5], list);
+
+        let mut list = LinkedList::<i32>::new();
+        let mut cursor = list.cursor_front_mut();
+        assert_eq!(None, cursor.peek());
+        cursor.insert_after(1);
+        cursor.move_next();
+        assert_eq!(Some(1), cursor.remove_current());
+        assert!(list.is_empty());
+        assert_eq!(None, tail_value(&list));
+    }
+
+    #[test]
+    fn test_send_sync() {
+        let list = linked_list![1, 2, 3];
+        let list = std::thread::spawn(move || {
+            assert_eq!(Some(&2), list.get(1));
+            list
+        })
+        .join()
+        .unwrap();
+        assert_eq!(linked_list![1, 2, 3], list);
+
+        std::thread::scope(|scope| {
+            scope.spawn(|| assert_eq!(6, list.iter().sum::<i32>()));
+            scope.spawn(|| assert_eq!(Some(&3), list.last()));
+        });
+    }
+
+    #[test]
+    fn test_insert_after_value() {
+        let mut list = linked_list![1, 3, 5];
+        list.insert_after_value(&1, 2).unwrap();
+        list.insert_after_value(&3, 4).unwrap();
+        list.insert_after_value(&5, 6).unwrap();
+        assert_eq!(linked_list![1, 2, 3, 4, 5, 6], list);
+        assert_eq!(6, list.len());
+        assert_eq!(Some(&6), tail_value(&list));
+
+        assert_eq!(
+            Err(LinkedListError::NotFound),
+            list.insert_after_value(&7, 8)
+        );
+        assert_eq!(6, list.len());
+
+        let mut list = LinkedList::<i32>::new();
+        assert_eq!(
+            Err(LinkedListError::NotFound),
+            list.insert_after_value(&1, 2)
+        );
+        assert!(list.is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let list = linked_list![1, 2, 3];
+        let json = serde_json::to_string(&list).unwrap();
+        assert_eq!("[1,2,3]", json);
+        let decoded: LinkedList<i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(list, decoded);
+
+        let list = LinkedList::<String>::new();
+        let json = serde_json::to_string(&list).unwrap();
+        assert_eq!("[]", json);
+        let decoded: LinkedList<String> = serde_json::from_str(&json).unwrap();
+        assert!(decoded.is_empty());
+    }
+
+    #[test]
+    fn test_hash() {
+        let mut set = HashSet::new();
+        set.insert(linked_list![1, 2, 3]);
+        set.insert(linked_list![1, 2, 3]);
+        assert_eq!(1, set.len());
+
+        set.insert(linked_list![1, 2]);
+        set.insert(LinkedList::new());
+        assert_eq!(3, set.len());
+        assert!(set.contains(&linked_list![1, 2]));
+    }
+
+    #[test]
+    fn test_ord() {
+        assert!(linked_list![1, 2] < linked_list![1, 3]);
+        assert!(linked_list![1] < linked_list![1, 2]);
+        assert!(LinkedList::new() < linked_list![0]);
+        assert_eq!(
+            Ordering::Equal,
+            linked_list![1, 2, 3].cmp(&linked_list![1, 2, 3])
+        );
+        assert_eq!(
+            Some(Ordering::Greater),
+            linked_list![2.0].partial_cmp(&linked_list![1.0, 5.0])
+        );
+
+        let set: BTreeSet<_> = [linked_list![2], linked_list![1, 2], linked_list![1]]
+            .into_iter()
+            .collect();
+        let sorted: Vec<_> = set.into_iter().collect();
+        assert_eq!(
+            vec![linked_list![1], linked_list![1, 2], linked_list![2]],
+            sorted
+        );
+    }
+
+    #[test]
+    fn test_sort() {
+        let mut list = linked_list![3, 1, 4, 1, 5, 9, 2, 6];
+        list.sort();
+        assert_eq!(linked_list![1, 1, 2, 3, 4, 5, 6, 9], list);
+        assert_eq!(8, list.len());
+        assert_eq!(Some(&9), tail_value(&list));
+        list.push_back(10);
+        assert_eq!(Some(&10), list.get(8));
+
+        // pairs only compare by key, so equal keys must keep their order
+        #[derive(Debug, PartialEq, Eq)]
+        struct Pair(i32, char);
+        impl PartialOrd for Pair {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for Pair {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+        let mut list = linked_list![Pair(2, 'a'), Pair(1, 'b'), Pair(2, 'c'), Pair(1, 'd')];
+        list.sort();
+        let tags: Vec<char> = list.iter().map(|pair| pair.1).collect();
+        assert_eq!(vec!['b', 'd', 'a', 'c'], tags);
+
+        let mut list = LinkedList::<i32>::new();
+        list.sort();
+        assert!(list.is_empty());
+        assert_eq!(None, tail_value(&list));
+
+        let mut list = linked_list![1];
+        list.sort();
+        assert_eq!(linked_list![1], list);
+        assert_eq!(Some(&1), tail_value(&list));
+    }
+
+    #[test]
+    fn test_sort_by() {
+        let mut list = linked_list![3, 1, 4, 1, 5];
+        list.sort_by(|a, b| b.cmp(a));
+        assert_eq!(linked_list![5, 4, 3, 1, 1], list);
+        assert_eq!(Some(&1), tail_value(&list));
+
+        struct Person {
+            name: &'static str,
+            age: u32,
+        }
+        let mut list = linked_list![
+            Person { name: "c", age: 30 },
+            Person { name: "a", age: 50 },
+            Person { name: "b", age: 20 },
+        ];
+        list.sort_by(|a, b| a.age.cmp(&b.age));
+        let names: Vec<&str> = list.iter().map(|person| person.name).collect();
+        assert_eq!(vec!["b", "c", "a"], names);
+
+        list.sort_by(|a, b| a.name.cmp(b.name));
+        let names: Vec<&str> = list.iter().map(|person| person.name).collect();
+        assert_eq!(vec!["a", "b", "c"], names);
+    }
+
+    #[test]
+    fn test_dedup_by_key() {
+        let mut list = linked_list![1, -1, 2, -2, -2, 3, 1];
+        list.dedup_by_key(|value: &i32| value.abs());
+        assert_eq!(linked_list![1, 2, 3, 1], list);
+        assert_eq!(Some(&1), tail_value(&list));
+
+        // the first value of a run is the one kept
+        let mut list = linked_list![-2, 2, 1];
+        list.dedup_by_key(|value: &i32| value.abs());
+        assert_eq!(linked_list![-2, 1], list);
+
+        let mut list = LinkedList::<i32>::new();
+        list.dedup_by_key(|value| *value);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_dedup() {
+        let mut list = linked_list![1, 1, 2, 3, 3, 3, 1];
+        list.dedup();
+        assert_eq!(linked_list![1, 2, 3, 1], list);
+        assert_eq!(4, list.len());
+        assert_eq!(Some(&1), tail_value(&list));
+
+        let mut list = linked_list![1, 2, 2];
+        list.dedup();
+        assert_eq!(linked_list![1, 2], list);
+        assert_eq!(Some(&2), tail_value(&list));
+        list.push_back(3);
+        assert_eq!(linked_list![1, 2, 3], list);
+
+        let mut list = LinkedList::<i32>::new();
+        list.dedup();
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_peek() {
+        let mut list = LinkedList::<i32>::new();
+        assert_eq!(None, list.peek_front());
+        assert_eq!(None, list.peek_back());
+
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        assert_eq!(Some(&1), list.peek_front());
+        assert_eq!(Some(&3), list.peek_back());
+        assert_eq!(3, list.len());
+
+        assert_eq!(list.peek_front().copied(), list.pop_front());
+        assert_eq!(list.peek_back().copied(), list.pop_back());
+        assert_eq!(Some(&2), list.peek_front());
+        assert_eq!(Some(&2), list.peek_back());
+    }
+
+    #[test]
+    fn test_extract_if() {
+        let mut list = linked_list![1, 2, 3, 4, 5];
+        let odds = list.extract_if(|value| value % 2 == 1);
+        assert_eq!(vec![1, 3, 5], odds);
+        assert_eq!(linked_list![2, 4], list);
+        assert_eq!(2, list.len());
+        assert_eq!(Some(&4), tail_value(&list));
+
+        assert!(list.extract_if(|_| false).is_empty());
+        assert_eq!(vec![2, 4], list.extract_if(|_| true));
+        assert!(list.is_empty());
+        assert_eq!(None, tail_value(&list));
+    }
+
+    #[test]
+    fn test_index_zero_on_empty_list() {
+        let mut list = LinkedList::<i32>::new();
+        assert_eq!(None, list.get(0));
+        assert_eq!(None, list.get_mut(0));
+        assert_eq!(Err(LinkedListError::EmptyList), list.remove(0));
+        assert!(list.is_empty());
+
+        list.add_at(1, 0).unwrap();
+        assert_eq!(linked_list![1], list);
+        assert_eq!(Some(&1), tail_value(&list));
+
+        list.remove(0).unwrap();
+        assert_eq!(None, list.get(0));
+        assert_eq!(Err(LinkedListError::EmptyList), list.remove(0));
+    }
+
+    #[test]
+    fn test_add_at_end() {
+        let mut list = linked_list![0, 2];
+        list.add_at(3, list.len()).unwrap();
+        assert_eq!(linked_list![0, 2, 3], list);
+        assert_eq!(Some(&3), tail_value(&list));
+
+        list.add_at(1, 1).unwrap();
+        assert_eq!(linked_list![0, 1, 2, 3], list);
+
+        assert_eq!(
+            Err(LinkedListError::IndexOutOfBounds { index: 5, len: 4 }),
+            list.add_at(5, list.len() + 1)
+        );
+        assert_eq!(linked_list![0, 1, 2, 3], list);
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut list = linked_list![1, 3];
+        list.insert(0, 0).unwrap();
+        list.insert(2, 2).unwrap();
+        list.insert(4, 4).unwrap();
+        assert_eq!(linked_list![0, 1, 2, 3, 4], list);
+        assert_eq!(Some(&4), tail_value(&list));
+
+        assert_eq!(
+            Err(LinkedListError::IndexOutOfBounds { index: 6, len: 5 }),
+            list.insert(6, 6)
+        );
+        assert_eq!(5, list.len());
+    }
+
+    #[test]
+    fn test_remove_returns_value() {
+        let mut list = linked_list![String::from("a"), String::from("b"), String::from("c")];
+        assert_eq!(Ok(String::from("b")), list.remove(1));
+        assert_eq!(Ok(String::from("c")), list.remove(1));
+        assert_eq!(Ok(String::from("a")), list.remove(0));
+        assert_eq!(Err(LinkedListError::EmptyList), list.remove(0));
+    }
+
+    #[test]
+    fn test_clone() {
+        let list = linked_list![1, 2, 3];
+        let mut cloned = list.clone();
+        assert_eq!(list, cloned);
+        cloned.push_back(4);
+        assert_eq!(linked_list![1, 2, 3], list);
+    }
+
+    #[test]
+    fn test_clone_from() {
+        let source = linked_list![1, 2, 3];
+
+        // shorter
+        let mut list = linked_list![9];
+        list.clone_from(&source);
+        assert_eq!(source, list);
+        assert_eq!(3, list.len());
+        assert_eq!(Some(&3), tail_value(&list));
+
+        // longer
+        let mut list = linked_list![9, 9, 9, 9, 9];
+        list.clone_from(&source);
+        assert_eq!(source, list);
+        assert_eq!(3, list.len());
+        assert_eq!(Some(&3), tail_value(&list));
+
+        // equal, which reuses every node
+        let mut list = linked_list![9, 9, 9];
+        let first_ptr: *const i32 = list.get(0).unwrap();
+        list.clone_from(&source);
+        assert_eq!(source, list);
+        assert_eq!(first_ptr, list.get(0).unwrap() as *const i32);
+
+        let mut list = linked_list![9, 9];
+        list.clone_from(&LinkedList::new());
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_from_array() {
+        let list = LinkedList::<i32>::from([]);
+        assert!(list.is_empty());
+
+        let list = LinkedList::from([1]);
+        assert_eq!(linked_list![1], list);
+
+        let list = LinkedList::from([1, 2, 3, 4]);
+        assert_eq!(linked_list![1, 2, 3, 4], list);
+        assert_eq!(4, list.len());
+    }
+
+    #[test]
+    fn test_count_by() {
+        let list = linked_list![1, 2, 3, 4, 5, 6];
+        assert_eq!(3, list.count_by(|value| value % 2 == 0));
+        assert_eq!(0, list.count_by(|_| false));
+        assert_eq!(0, LinkedList::<i32>::new().count_by(|_| true));
+    }
+
+    #[test]
+    fn test_fold() {
+        let list = linked_list![1, 2, 3, 4];
+        assert_eq!(10, list.fold(0, |sum, value| sum + value));
+        assert_eq!(
+            "1234",
+            list.fold(String::new(), |text, value| text + &value.to_string())
+        );
+        assert_eq!(
+            7,
+            LinkedList::<i32>::new().fold(7, |sum, value| sum + value)
+        );
+    }
+
+    #[test]
+    fn test_for_each() {
+        let list = linked_list![1, 2, 3];
+        let mut seen = Vec::new();
+        list.for_each(|value| seen.push(*value));
+        assert_eq!(vec![1, 2, 3], seen);
+
+        let mut calls = 0;
+        LinkedList::<i32>::new().for_each(|_| calls += 1);
+        assert_eq!(0, calls);
+    }
+
+    #[test]
+    fn test_map() {
+        let list = linked_list![1, 2, 3];
+        let strings = list.map(|value| value.to_string());
+        assert_eq!(
+            linked_list![String::from("1"), String::from("2"), String::from("3")],
+            strings
+        );
+        assert_eq!(linked_list![1, 2, 3], list);
+
+        assert!(LinkedList::<i32>::new().map(|value| value * 2).is_empty());
+    }
+
+    #[test]
+    fn test_filter() {
+        let list = linked_list![1, 2, 3, 4, 5, 6];
+        let evens = list.filter(|value| value % 2 == 0);
+        assert_eq!(linked_list![2, 4, 6], evens);
+        assert_eq!(Some(&6), tail_value(&evens));
+        assert_eq!(linked_list![1, 2, 3, 4, 5, 6], list);
+
+        assert!(list.filter(|_| false).is_empty());
+    }
+
+    #[test]
+    fn test_try_get() {
+        let list = linked_list![1, 2, 3];
+        assert_eq!(Ok(&1), list.try_get(0));
+        assert_eq!(Ok(&3), list.try_get(2));
+        assert_eq!(
+            Err(LinkedListError::IndexOutOfBounds { index: 3, len: 3 }),
+            list.try_get(3)
+        );
+        assert_eq!(
+            Err(LinkedListError::IndexOutOfBounds { index: 0, len: 0 }),
+            LinkedList::<i32>::new().try_get(0)
+        );
+    }
+
+    #[test]
+    fn test_set() {
+        let mut list = linked_list![1, 2, 3];
+        assert_eq!(Ok(2), list.set(1, 20));
+        assert_eq!(linked_list![1, 20, 3], list);
+        assert_eq!(
+            Err(LinkedListError::IndexOutOfBounds { index: 3, len: 3 }),
+            list.set(3, 4)
+        );
+        assert_eq!(linked_list![1, 20, 3], list);
+    }
+
+    #[test]
+    fn test_with_arena() {
+        let mut list = LinkedList::with_arena();
+        for value in 0..1000 {
+            list.push_back(value);
+        }
+        list.push_front(-1);
+        list.insert(500, 500).unwrap();
+        assert_eq!(1002, list.len());
+        assert_eq!(Some(&-1), list.first());
+        assert_eq!(Some(&500), list.get(500));
+        assert_eq!(Some(&999), list.last());
+
+        assert_eq!(Some(-1), list.pop_front());
+        assert_eq!(Some(999), list.pop_back());
+        assert_eq!(Ok(500), list.remove(499));
+        assert_eq!((0..999).collect::<LinkedList<_>>(), list);
+
+        // the split off nodes live in the arena of the original list
+        let other = list.split_off(500).unwrap();
+        drop(list);
+        assert_eq!((500..999).collect::<LinkedList<_>>(), other);
+
+        // clearing keeps allocating from an arena
+        let mut list = LinkedList::with_arena();
+        list.push_back(1);
+        list.clear();
+        list.push_back(2);
+        assert!(
+            list.arena
+                .as_ref()
+                .is_some_and(|arena| arena.is_allocating())
+        );
+        assert_eq!(linked_list![2], list);
+    }
+
+    #[test]
+    fn test_with_arena_moves_between_lists() {
+        let tracker = Rc::new(());
+        let mut arena_list = LinkedList::with_arena();
+        for _ in 0..100 {
+            arena_list.push_back(Rc::clone(&tracker));
+        }
+
+        let mut list = LinkedList::new();
+        list.push_back(Rc::clone(&tracker));
+        list.append(arena_list);
+        assert_eq!(102, Rc::strong_count(&tracker));
+
+        // the appended nodes are still freed without being deallocated one by one
+        list.truncate(50);
+        assert_eq!(51, Rc::strong_count(&tracker));
+        list.push_back(Rc::clone(&tracker));
+        assert_eq!(52, Rc::strong_count(&tracker));
+        drop(list);
+        assert_eq!(1, Rc::strong_count(&tracker));
+    }
+
+    #[test]
+    fn test_rposition() {
+        let list = linked_list![1, 2, 1, 3, 1];
+        assert_eq!(Some(4), list.rposition(|value| *value == 1));
+        assert_eq!(Some(3), list.rposition(|value| *value == 3));
+        assert_eq!(None, list.rposition(|value| *value == 4));
+        assert_eq!(None, LinkedList::<i32>::new().rposition(|_| true));
+    }
+
+    #[test]
+    fn test_min_max_value() {
+        let list = linked_list![4, -2, 9, 0, 9, 3];
+        assert_eq!(Some(&9), list.max_value());
+        assert_eq!(Some(&-2), list.min_value());
+
+        let empty = LinkedList::<i32>::new();
+        assert_eq!(None, empty.max_value());
+        assert_eq!(None, empty.min_value());
+    }
+
+    #[test]
+    fn test_flatten() {
+        let lists = linked_list![
+            linked_list![1, 2],
+            linked_list![3],
+            LinkedList::new(),
+            linked_list![4, 5]
+        ];
+        let flat_list = lists.flatten();
+        assert_eq!(linked_list![1, 2, 3, 4, 5], flat_list);
+        assert_eq!(Some(&5), tail_value(&flat_list));
+
+        // every value is moved over exactly once
+        let tracker = Rc::new(());
+        let lists: LinkedList<LinkedList<Rc<()>>> = (0..4)
+            .map(|len| (0..len).map(|_| Rc::clone(&tracker)).collect())
+            .collect();
+        assert_eq!(7, Rc::strong_count(&tracker));
+        let flat_list = lists.flatten();
+        assert_eq!(6, flat_list.len());
+        assert_eq!(7, Rc::strong_count(&tracker));
+        drop(flat_list);
+        assert_eq!(1, Rc::strong_count(&tracker));
+    }
+
+    #[test]
+    fn test_into_chunks() {
+        let chunks = linked_list![1, 2, 3, 4, 5].into_chunks(2);
+        assert_eq!(
+            vec![linked_list![1, 2], linked_list![3, 4], linked_list![5]],
+            chunks
+        );
+        for chunk in &chunks {
+            assert_eq!(chunk.last(), tail_value(chunk));
+        }
+
+        assert_eq!(vec![linked_list![1, 2]], linked_list![1, 2].into_chunks(2));
+        assert!(LinkedList::<i32>::new().into_chunks(3).is_empty());
+
+        let tracker = Rc::new(());
+        let list: LinkedList<Rc<()>> = (0..7).map(|_| Rc::clone(&tracker)).collect();
+        let chunks = list.into_chunks(3);
+        assert_eq!(3, chunks.len());
+        assert_eq!(8, Rc::strong_count(&tracker));
+        drop(chunks);
+        assert_eq!(1, Rc::strong_count(&tracker));
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must not be 0")]
+    fn test_into_chunks_zero_size() {
+        linked_list![1, 2].into_chunks(0);
+    }
+
+    #[test]
+    fn test_pairs() {
+        let list = linked_list![1, 2, 3, 4];
+        assert_eq!(
+            vec![(&1, &2), (&2, &3), (&3, &4)],
+            list.pairs().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![1, 1, 1],
+            list.pairs().map(|(a, b)| b - a).collect::<Vec<_>>()
+        );
+
+        assert_eq!(0, linked_list![1].pairs().count());
+        assert_eq!(0, LinkedList::<i32>::new().pairs().count());
+    }
+
+    #[test]
+    fn test_contains() {
+        let list = linked_list![1, 2, 3];
+        assert!(list.contains(&2));
+        assert!(!list.contains(&4));
+        assert!(!LinkedList::<i32>::new().contains(&1));
+    }
+
+    #[test]
+    fn test_values_stored_in_nodes() {
+        // every value lives directly inside of its node, and is moved back out unchanged
+        let tracker = Rc::new(());
+        let mut list = LinkedList::new();
+        list.push_back((1, Rc::clone(&tracker)));
+        list.push_front((0, Rc::clone(&tracker)));
+        list.add_at((2, Rc::clone(&tracker)), 2).unwrap();
+        assert_eq!(4, Rc::strong_count(&tracker));
+        assert_eq!(Some(&1), list.get(1).map(|(value, _)| value));
+
+        assert_eq!(Some(0), list.pop_front().map(|(value, _)| value));
+        assert_eq!(Ok(2), list.remove(1).map(|(value, _)| value));
+        assert_eq!(Some(1), list.pop_back().map(|(value, _)| value));
+        assert!(list.is_empty());
+        assert_eq!(1, Rc::strong_count(&tracker));
+    }
+
+    #[test]
+    fn test_operations_match_vec() {
+        let mut list = LinkedList::new();
+        let mut expected = Vec::new();
+
+        // mix every way of adding and removing values, checking against a Vec after each step
+        for value in 0..40 {
+            match value % 5 {
+                0 => {
+                    list.push_front(value);
+                    expected.insert(0, value);
+                }
+                1 | 2 => {
+                    list.push_back(value);
+                    expected.push(value);
+                }
+                3 => {
+                    let idx = expected.len() / 2;
+                    list.add_at(value, idx).unwrap();
+                    expected.insert(idx, value);
+                }
+                _ => {
+                    let idx = value % expected.len();
+                    assert_eq!(Ok(expected.remove(idx)), list.remove(idx));
+                }
+            }
+
+            assert_eq!(expected.len(), list.len());
+            assert_eq!(expected.last(), tail_value(&list));
+            for (idx, value) in expected.iter().enumerate() {
+                assert_eq!(Some(value), list.get(idx));
+            }
+        }
+
+        while let Some(value) = expected.pop() {
+            assert_eq!(Ok(value), list.remove(expected.len()));
+        }
+        assert!(list.is_empty());
+        assert_eq!(None, tail_value(&list));
+    }
+
+    #[test]
+    fn test_push_back_slice() {
+        let mut list = linked_list![0];
+        list.push_back_slice(&[1, 2, 3]);
+        assert_eq!(linked_list![0, 1, 2, 3], list);
+        assert_eq!(Some(&3), tail_value(&list));
+
+        list.push_back_slice(&[]);
+        assert_eq!(4, list.len());
+
+        let mut empty = LinkedList::new();
+        empty.push_back_slice(&[5]);
+        assert_eq!(linked_list![5], empty);
+    }
+
+    #[test]
+    fn test_prepend() {
+        let mut list = linked_list![3, 4];
+        list.prepend(linked_list![1, 2]);
+        assert_eq!(linked_list![1, 2, 3, 4], list);
+        assert_eq!(Some(&4), tail_value(&list));
+
+        list.prepend(LinkedList::new());
+        assert_eq!(4, list.len());
+
+        let mut empty = LinkedList::new();
+        empty.prepend(linked_list![1, 2]);
+        assert_eq!(linked_list![1, 2], empty);
+        assert_eq!(Some(&2), tail_value(&empty));
+
+        // every node is freed exactly once, including ones from an arena
+        let tracker = Rc::new(());
+        let mut arena_list = LinkedList::with_arena();
+        arena_list.push_back(Rc::clone(&tracker));
+        let mut list = linked_list![Rc::clone(&tracker)];
+        list.prepend(arena_list);
+        list.push_front(Rc::clone(&tracker));
+        assert_eq!(3, list.len());
+        assert_eq!(4, Rc::strong_count(&tracker));
+        drop(list);
+        assert_eq!(1, Rc::strong_count(&tracker));
+    }
+
+    #[test]
+    fn test_same_multiset() {
+        assert!(linked_list![1, 2, 3].same_multiset(&linked_list![3, 1, 2]));
+        assert!(linked_list![2, 1, 2].same_multiset(&linked_list![2, 2, 1]));
+        assert!(!linked_list![1, 2, 2].same_multiset(&linked_list![1, 2, 3]));
+        assert!(!linked_list![1, 2].same_multiset(&linked_list![1, 2, 2]));
+        assert!(LinkedList::<i32>::new().same_multiset(&LinkedList::new()));
+    }
+
+    #[test]
+    fn test_find() {
+        let list = linked_list![1, 4, 6, 9];
+        assert_eq!(Some(&4), list.find(|value| value % 2 == 0));
+        assert_eq!(None, list.find(|value| *value > 10));
+        assert_eq!(None, LinkedList::<i32>::new().find(|_| true));
+    }
+
+    #[test]
+    fn test_find_map() {
+        let list = linked_list!["a", "12", "b", "7"];
+        assert_eq!(Some(12), list.find_map(|value| value.parse::<i32>().ok()));
+        assert_eq!(None, list.find_map(|value| value.parse::<bool>().ok()));
+    }
+
+    #[test]
+    fn test_any() {
+        let list = linked_list![1, 2, 3, 4];
+        let mut checked = 0;
+        assert!(list.any(|value| {
+            checked += 1;
+            *value == 2
+        }));
+        assert_eq!(2, checked);
+        assert!(!list.any(|value| *value > 4));
+        assert!(!LinkedList::<i32>::new().any(|_| true));
+    }
+
+    #[test]
+    fn test_all() {
+        let list = linked_list![1, 2, 3, 4];
+        let mut checked = 0;
+        assert!(!list.all(|value| {
+            checked += 1;
+            *value < 3
+        }));
+        assert_eq!(3, checked);
+        assert!(list.all(|value| *value > 0));
+        assert!(LinkedList::<i32>::new().all(|_| false));
     }
 
     #[test]
-    fn test_get() {
-        let mut list = LinkedList::<i32>::new();
+    fn test_remove_first() {
+        let mut list = linked_list![1, 2, 3, 2];
+        assert_eq!(Some(2), list.remove_first(&2));
+        assert_eq!(linked_list![1, 3, 2], list);
+        assert_eq!(Some(2), list.remove_first(&2));
+        assert_eq!(linked_list![1, 3], list);
+        assert_eq!(Some(&3), tail_value(&list));
 
-        let mut result = list.get(0);
-        if result.is_some() {
-            assert!(false); // fail
+        assert_eq!(None, list.remove_first(&4));
+        assert_eq!(linked_list![1, 3], list);
+        assert_eq!(None, LinkedList::new().remove_first(&1));
+    }
+
+    #[test]
+    fn test_remove_all() {
+        let mut list = linked_list![1, 2, 1, 3, 1];
+        assert_eq!(3, list.remove_all(&1));
+        assert_eq!(linked_list![2, 3], list);
+        assert_eq!(2, list.len());
+        assert_eq!(Some(&3), tail_value(&list));
+
+        assert_eq!(0, list.remove_all(&4));
+        assert_eq!(1, list.remove_all(&3));
+        assert_eq!(Some(&2), tail_value(&list));
+    }
+
+    #[test]
+    fn test_invariants_hold_across_operations() {
+        // every mutating method that walks the list checks its length and tail
+        // in debug builds, so this only has to not panic
+        let mut list: LinkedList<i32> = (0..20).collect();
+        list.remove(19).unwrap();
+        list.remove(5).unwrap();
+        list.add_at(100, 10).unwrap();
+        list.insert_after_value(&100, 101).unwrap();
+        list.pop_back();
+        list.reverse();
+        list.sort();
+        list.push_back(50);
+        list.push_back(50);
+        list.dedup();
+        list.retain(|value| value % 3 != 0);
+        list.extract_if(|value| value % 7 == 0);
+        assert_eq!(1, list.remove_all(&101));
+        assert_eq!(Some(100), list.remove_first(&100));
+        let mut rest = list.split_off(3).unwrap();
+        rest.pop_back();
+        list.append(rest);
+        while list.pop_back().is_some() {}
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn test_invariant_checks_stay_linear() {
+        // none of these walk the whole list, so none of them may check it,
+        // or draining or building a list through them would be quadratic
+        let mut list: LinkedList<i32> = (0..100).collect();
+        for value in 100..150 {
+            list.add_at(value, 0).unwrap();
+            list.add_at(value, list.len()).unwrap();
+            list.add_at(value, 1).unwrap();
+            list.insert_after_value(&value, value).unwrap();
         }
-        list.push_back(0);
-        assert_eq!(0, *list.get(0).unwrap());
+        while list.len() > 3 {
+            list.remove(0).unwrap();
+            let front = list[0];
+            assert_eq!(Some(front), list.remove_first(&front));
+            list.remove(1).unwrap();
+        }
+        let list: LinkedList<i32> = (0..100).collect();
+        assert_eq!(50, list.into_chunks(2).len());
+        assert_eq!(0, INVARIANT_CHECKS.get());
+
+        // reaching the tail walked the whole list already, so it is checked
+        let mut list = linked_list![1, 2, 3];
+        list.remove(2).unwrap();
+        list.remove_first(&2).unwrap();
+        list.insert_after_value(&1, 4).unwrap();
+        assert_eq!(3, INVARIANT_CHECKS.get());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "length does not match the number of nodes")]
+    fn test_invariants_catch_bad_length() {
+        let mut list = linked_list![1, 2, 3];
+        list.length = 4;
+        list.reverse();
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn test_invariants_catch_cycle() {
+        let mut list = linked_list![1, 2, 3];
+        // SAFETY: the cycle is broken again before the list is used or dropped
+        unsafe { make_cycle(&mut list, 1) };
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            list.debug_assert_invariants();
+        }));
+        break_cycle(&mut list);
+
+        let message = *result.unwrap_err().downcast::<&str>().unwrap();
+        assert_eq!("the nodes of the list form a cycle", message);
+        list.debug_assert_invariants();
+    }
+
+    #[test]
+    fn test_get_range() {
+        let list = linked_list![0, 1, 2, 3, 4];
+        assert_eq!(Some(vec![&1, &2, &3]), list.get_range(1, 4));
+        assert_eq!(Some(vec![&0, &1, &2, &3, &4]), list.get_range(0, 5));
+        assert_eq!(Some(Vec::<&i32>::new()), list.get_range(5, 5));
+        assert_eq!(None, list.get_range(2, 6));
+        assert_eq!(None, list.get_range(3, 2));
+    }
+
+    #[test]
+    fn test_split_at() {
+        let list = linked_list![1, 2, 3, 4];
+        assert_eq!(Some((vec![&1, &2], vec![&3, &4])), list.split_at(2));
+        assert_eq!(Some((vec![], vec![&1, &2, &3, &4])), list.split_at(0));
+        assert_eq!(Some((vec![&1, &2, &3, &4], vec![])), list.split_at(4));
+        assert_eq!(None, list.split_at(5));
+        assert_eq!(linked_list![1, 2, 3, 4], list);
+    }
+
+    #[test]
+    fn test_compact() {
+        let tracker = Rc::new(());
+        let mut list: LinkedList<(i32, Rc<()>)> =
+            (0..10).map(|value| (value, Rc::clone(&tracker))).collect();
+        list.retain(|(value, _)| value % 2 == 0);
+        list.compact();
+        assert_eq!(
+            vec![0, 2, 4, 6, 8],
+            list.iter().map(|(value, _)| *value).collect::<Vec<_>>()
+        );
+        assert_eq!(Some(&8), tail_value(&list).map(|(value, _)| value));
+        // the old nodes were freed, only the new ones hold a clone
+        assert_eq!(6, Rc::strong_count(&tracker));
+
+        // the new nodes sit next to each other in list order
+        let nodes: Vec<_> = std::iter::successors(list.head, |node_ptr| {
+            // SAFETY: every node reachable from the head is valid
+            unsafe { (*node_ptr.as_ptr()).next }
+        })
+        .collect();
+        for pair in nodes.windows(2) {
+            // SAFETY: both nodes are in the same buffer
+            assert_eq!(1, unsafe { pair[1].offset_from(pair[0]) });
+        }
+
+        let mut arena_list = LinkedList::with_arena();
+        arena_list.extend(0..100);
+        arena_list.truncate(3);
+        arena_list.compact();
+        assert_eq!(linked_list![0, 1, 2], arena_list);
+        assert!(
+            arena_list
+                .arena
+                .as_ref()
+                .is_some_and(|arena| arena.is_allocating())
+        );
+        arena_list.push_back(3);
+        assert_eq!(Some(&3), tail_value(&arena_list));
+    }
+
+    #[test]
+    fn test_eq_slice_and_vec() {
+        let list = linked_list![1, 2, 3];
+        assert_eq!(list, vec![1, 2, 3]);
+        assert_eq!(list, [1, 2, 3][..]);
+        assert_ne!(list, vec![1, 2]);
+        assert_ne!(list, vec![1, 2, 4]);
+        assert_ne!(list, [1, 2, 3, 4][..]);
+        assert_eq!(LinkedList::<i32>::new(), Vec::new());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("1 -> 2 -> 3", linked_list![1, 2, 3].to_string());
+        assert_eq!("a", linked_list!["a"].to_string());
+        assert_eq!("<empty>", LinkedList::<i32>::new().to_string());
+    }
+
+    #[test]
+    fn test_contains_subsequence() {
+        let list = linked_list![1, 2, 3, 4];
+        assert!(list.contains_subsequence(&[2, 3]));
+        assert!(!list.contains_subsequence(&[3, 2]));
+        assert!(list.contains_subsequence(&[3, 4]));
+        assert!(list.contains_subsequence(&[1, 2, 3, 4]));
+        assert!(!list.contains_subsequence(&[1, 2, 3, 4, 5]));
+        assert!(!list.contains_subsequence(&[1, 3]));
+        assert!(list.contains_subsequence(&[]));
+        assert!(LinkedList::<i32>::new().contains_subsequence(&[]));
+        assert!(!LinkedList::new().contains_subsequence(&[1]));
+
+        // a partial match has to be retried from the next node
+        assert!(linked_list![1, 1, 2].contains_subsequence(&[1, 2]));
+    }
+
+    #[test]
+    fn test_starts_with() {
+        let list = linked_list![1, 2, 3];
+        assert!(list.starts_with(&[1, 2]));
+        assert!(list.starts_with(&[1, 2, 3]));
+        assert!(list.starts_with(&[]));
+        assert!(!list.starts_with(&[2, 3]));
+        assert!(!list.starts_with(&[1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn test_ends_with() {
+        let list = linked_list![1, 2, 3];
+        assert!(list.ends_with(&[2, 3]));
+        assert!(list.ends_with(&[1, 2, 3]));
+        assert!(list.ends_with(&[]));
+        assert!(!list.ends_with(&[1, 2]));
+        assert!(!list.ends_with(&[0, 1, 2, 3]));
+        assert!(LinkedList::<i32>::new().ends_with(&[]));
+    }
+
+    #[test]
+    fn test_get_many_mut() {
+        let mut list = linked_list![0, 1, 2, 3];
+        let [last, first] = list.get_many_mut([3, 0]).unwrap();
+        std::mem::swap(last, first);
+        assert_eq!(linked_list![3, 1, 2, 0], list);
+
+        if let Some([a, b, c]) = list.get_many_mut([1, 2, 0]) {
+            *a += 10;
+            *b += 20;
+            *c += 30;
+        }
+        assert_eq!(linked_list![33, 11, 22, 0], list);
+
+        assert!(list.get_many_mut([1, 1]).is_none());
+        assert!(list.get_many_mut([0, 4]).is_none());
+        assert!(list.get_many_mut([]).is_some());
+    }
+
+    #[test]
+    fn test_with() {
+        let list = LinkedList::new().with(1).with(2).with(3);
+        assert_eq!(linked_list![1, 2, 3], list);
+        assert_eq!(Some(&3), tail_value(&list));
+    }
+
+    #[test]
+    fn test_first_mut_last_mut() {
+        let mut list = linked_list![1, 2, 3];
+        *list.first_mut().unwrap() = 10;
+        *list.last_mut().unwrap() += 20;
+        assert_eq!(linked_list![10, 2, 23], list);
+        assert_eq!(Some(&10), list.first());
+        assert_eq!(Some(&23), list.last());
+
+        let mut single = linked_list![5];
+        *single.last_mut().unwrap() = 6;
+        assert_eq!(Some(&mut 6), single.first_mut());
+
+        let mut empty = LinkedList::<i32>::new();
+        assert_eq!(None, empty.first_mut());
+        assert_eq!(None, empty.last_mut());
+    }
+
+    #[test]
+    fn test_partition() {
+        let (evens, odds) = linked_list![1, 2, 3, 4].partition(|value| value % 2 == 0);
+        assert_eq!(linked_list![2, 4], evens);
+        assert_eq!(linked_list![1, 3], odds);
+        assert_eq!(Some(&4), tail_value(&evens));
+        assert_eq!(Some(&3), tail_value(&odds));
+
+        let (all, none) = linked_list![1, 2].partition(|_| true);
+        assert_eq!(2, all.len());
+        assert!(none.is_empty());
+
+        // every node ends up in exactly one of the lists, including arena nodes
+        let tracker = Rc::new(());
+        let mut list = LinkedList::with_arena();
+        list.extend((0..10).map(|value| (value, Rc::clone(&tracker))));
+        let (mut small, big) = list.partition(|(value, _)| *value < 3);
+        assert_eq!(3, small.len());
+        assert_eq!(7, big.len());
+        assert_eq!(11, Rc::strong_count(&tracker));
+        drop(big);
+        small.push_back((3, Rc::clone(&tracker)));
+        assert_eq!(5, Rc::strong_count(&tracker));
+        drop(small);
+        assert_eq!(1, Rc::strong_count(&tracker));
+    }
+
+    #[test]
+    fn test_zip_with() {
+        let sums = linked_list![1, 2, 3].zip_with(&linked_list![10, 20], |a, b| a + b);
+        assert_eq!(linked_list![11, 22], sums);
+
+        let labels =
+            linked_list![1, 2].zip_with(&linked_list!["a", "b", "c"], |n, s| format!("{s}{n}"));
+        assert_eq!(linked_list!["a1".to_string(), "b2".to_string()], labels);
+
+        assert!(
+            linked_list![1]
+                .zip_with(&LinkedList::<i32>::new(), |a, b| a * b)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_get_from_end() {
+        let list = linked_list![1, 2, 3, 4];
+        assert_eq!(list.last(), list.get_from_end(0));
+        assert_eq!(Some(&3), list.get_from_end(1));
+        assert_eq!(list.first(), list.get_from_end(list.len() - 1));
+        assert_eq!(None, list.get_from_end(list.len()));
+        assert_eq!(None, LinkedList::<i32>::new().get_from_end(0));
+    }
+
+    /// Points the tail of the list back at the node at `idx`, making a cycle.
+    ///
+    /// # Safety
+    /// The cycle must be broken with `break_cycle` before the list is used
+    /// for anything else, or dropped.
+    unsafe fn make_cycle<StoreType>(list: &mut LinkedList<StoreType>, idx: usize) {
+        let target = list.get_node_at(idx);
+        // SAFETY: the tail is valid for a non-empty list
+        unsafe { (*list.tail.unwrap().as_ptr()).next = target };
+    }
+
+    /// Undoes `make_cycle`, so the tail points at nothing again.
+    fn break_cycle<StoreType>(list: &mut LinkedList<StoreType>) {
+        // SAFETY: the tail is valid for a non-empty list
+        unsafe { (*list.tail.unwrap().as_ptr()).next = None };
+    }
+
+    #[test]
+    fn test_has_cycle() {
+        assert!(!LinkedList::<i32>::new().has_cycle());
+        for len in 1..6 {
+            let mut list: LinkedList<usize> = (0..len).collect();
+            assert!(!list.has_cycle());
+
+            for idx in 0..len {
+                // SAFETY: the cycle is broken again right after checking it
+                unsafe { make_cycle(&mut list, idx) };
+                assert!(list.has_cycle());
+                break_cycle(&mut list);
+            }
+
+            assert!(!list.has_cycle());
+            assert_eq!(len, list.iter().count());
+        }
+    }
+
+    #[test]
+    fn test_into_vec() {
+        assert_eq!(vec![1, 2, 3], linked_list![1, 2, 3].into_vec());
+        assert_eq!(Vec::<i32>::new(), LinkedList::<i32>::new().into_vec());
+
+        // no Clone needed, the values are moved out
+        let tracker = Rc::new(());
+        let list = linked_list![Rc::clone(&tracker), Rc::clone(&tracker)];
+        let values = list.into_vec();
+        assert_eq!(3, Rc::strong_count(&tracker));
+        drop(values);
+        assert_eq!(1, Rc::strong_count(&tracker));
+    }
+
+    #[test]
+    fn test_get_or_insert_with() {
+        let mut list = linked_list![1, 2];
+        *list.get_or_insert_with(0, || unreachable!()) += 10;
+        assert_eq!(linked_list![11, 2], list);
+
+        assert_eq!(&mut 3, list.get_or_insert_with(2, || 3));
+        *list.get_or_insert_with(3, || 4) *= 10;
+        assert_eq!(linked_list![11, 2, 3, 40], list);
+        assert_eq!(Some(&40), tail_value(&list));
+
+        let mut list = LinkedList::new();
+        assert_eq!(&mut 1, list.get_or_insert_with(0, || 1));
+        assert_eq!(linked_list![1], list);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 2 but the index is 3")]
+    fn test_get_or_insert_with_past_end() {
+        linked_list![1, 2].get_or_insert_with(3, || 3);
+    }
+
+    #[test]
+    fn test_iter_rev() {
+        let list = linked_list![1, 2, 3, 4];
+        let mut forward: Vec<_> = list.iter().collect();
+        forward.reverse();
+        assert_eq!(forward, list.iter().rev().collect::<Vec<_>>());
+        assert_eq!(None, LinkedList::<i32>::new().iter().next_back());
+
+        // both ends stop once they meet in the middle
+        let mut iter = list.iter();
+        assert_eq!(Some(&1), iter.next());
+        assert_eq!(Some(&4), iter.next_back());
+        assert_eq!(Some(&2), iter.next());
+        assert_eq!(Some(&3), iter.next_back());
+        assert_eq!(None, iter.next());
+        assert_eq!(None, iter.next_back());
+    }
+
+    #[test]
+    fn test_exact_size_iterators() {
+        let list = linked_list![1, 2, 3, 4];
+        let mut iter = list.iter();
+        assert_eq!(list.len(), iter.len());
+        iter.next();
+        assert_eq!(3, iter.len());
+        iter.next_back();
+        assert_eq!((2, Some(2)), iter.size_hint());
+        iter.by_ref().for_each(drop);
+        assert_eq!(0, iter.len());
+
+        let mut into_iter = list.into_iter();
+        assert_eq!(4, into_iter.len());
+        into_iter.next();
+        assert_eq!(3, into_iter.len());
+        assert_eq!(vec![2, 3, 4], into_iter.collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_split_first() {
+        let list = linked_list![1, 2, 3];
+        let (first, rest) = list.split_first().unwrap();
+        assert_eq!(&1, first);
+        assert_eq!(vec![&2, &3], rest.iter().collect::<Vec<_>>());
+        assert_eq!(Some(&3), rest.get(1));
+        assert_eq!(None, rest.get(2));
+        assert_eq!(2, rest.len());
+
+        let single = linked_list![1];
+        let (_, rest) = single.split_first().unwrap();
+        assert!(rest.is_empty());
+        assert_eq!(None, rest.iter().next());
+        assert!(LinkedList::<i32>::new().split_first().is_none());
+    }
+
+    #[test]
+    fn test_split_last() {
+        let list = linked_list![1, 2, 3];
+        let (last, rest) = list.split_last().unwrap();
+        assert_eq!(&3, last);
+        assert_eq!(vec![&1, &2], rest.iter().collect::<Vec<_>>());
+        assert_eq!(vec![&2, &1], rest.iter().rev().collect::<Vec<_>>());
+        assert_eq!(None, rest.get(2));
+        assert!(LinkedList::<i32>::new().split_last().is_none());
+    }
+
+    #[test]
+    fn test_pool_reuses_freed_nodes() {
+        let mut list = LinkedList::new();
         list.push_back(1);
-        assert_eq!(1, *list.get(1).unwrap());
+        let first_node = list.head;
+        assert_eq!(Some(1), list.pop_front());
+        assert_eq!(1, list.free_length);
+
+        // the freed node is handed out again instead of allocating a new one
         list.push_back(2);
-        assert_eq!(2, *list.get(2).unwrap());
-        list.push_back(3);
-        assert_eq!(3, *list.get(3).unwrap());
-        list.push_back(4);
-        assert_eq!(4, *list.get(4).unwrap());
-        result = list.get(5);
-        if result.is_some() {
-            assert!(false); // fail
+        assert_eq!(first_node, list.head);
+        assert_eq!(0, list.free_length);
+        assert_eq!(vec![2], list.iter().copied().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_pool_is_capped() {
+        let mut list: LinkedList<i32> = (0..100).collect();
+        while list.pop_front().is_some() {}
+        assert_eq!(POOL_CAPACITY, list.free_length);
+
+        list.drain_pool();
+        assert_eq!(0, list.free_length);
+        assert!(list.free.is_none());
+
+        // pushing after draining still works
+        list.push_back(7);
+        assert_eq!(Some(&7), list.get(0));
+    }
+
+    #[test]
+    fn test_pool_reuses_arena_nodes() {
+        let mut list = LinkedList::with_arena();
+        list.extend(0..10);
+        let nodes: HashSet<_> = std::iter::successors(list.head, |node_ptr| {
+            // SAFETY: every node reachable from the head is valid
+            unsafe { (*node_ptr.as_ptr()).next }
+        })
+        .collect();
+
+        // used as a queue, the list keeps cycling through the same nodes
+        // instead of taking new ones from the arena
+        for _ in 0..1_000 {
+            let value = list.pop_front().unwrap();
+            list.push_back(value);
+        }
+        assert!(
+            std::iter::successors(list.head, |node_ptr| {
+                // SAFETY: every node reachable from the head is valid
+                unsafe { (*node_ptr.as_ptr()).next }
+            })
+            .all(|node_ptr| nodes.contains(&node_ptr))
+        );
+
+        // draining leaves the memory of arena nodes to the arena
+        while list.pop_front().is_some() {}
+        list.drain_pool();
+        list.extend(0..3);
+        assert_eq!(linked_list![0, 1, 2], list);
+    }
+
+    #[test]
+    fn test_pool_does_not_keep_values_alive() {
+        let shared = Rc::new(5);
+        let mut list = LinkedList::new();
+        list.push_back(Rc::clone(&shared));
+        list.push_back(Rc::clone(&shared));
+        assert_eq!(3, Rc::strong_count(&shared));
+
+        drop(list.pop_back());
+        drop(list.remove(0));
+        assert_eq!(2, list.free_length);
+        assert_eq!(1, Rc::strong_count(&shared));
+    }
+
+    #[test]
+    fn test_get_bounded() {
+        let mut list = linked_list![10, 20, 30];
+        assert_eq!(Some(&10), list.get_bounded(0, 0));
+        assert_eq!(Some(&30), list.get_bounded(2, 2));
+        assert_eq!(Some(&30), list.get_bounded(2, 100));
+        assert_eq!(None, list.get_bounded(2, 1));
+        assert_eq!(None, list.get_bounded(3, 100));
+
+        // a cycle can't make it walk past the bound
+        // SAFETY: the cycle is broken again right after checking it
+        unsafe { make_cycle(&mut list, 0) };
+        assert_eq!(Some(&10), list.get_bounded(3, 3));
+        assert_eq!(None, list.get_bounded(usize::MAX, 1_000));
+        break_cycle(&mut list);
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let mut next = 0;
+        let list = LinkedList::from_fn(|| {
+            if next < 3 {
+                next += 1;
+                Some(next - 1)
+            } else {
+                None
+            }
+        });
+        assert_eq!(linked_list![0, 1, 2], list);
+        assert_eq!(Some(&2), tail_value(&list));
+
+        assert!(LinkedList::<i32>::from_fn(|| None).is_empty());
+    }
+
+    #[test]
+    fn test_repeat() {
+        assert_eq!(linked_list![7, 7, 7], LinkedList::repeat(7, 3));
+        assert!(LinkedList::repeat(7, 0).is_empty());
+
+        // the original value ends up in the list instead of being dropped
+        let tracker = Rc::new(());
+        let list = LinkedList::repeat(Rc::clone(&tracker), 2);
+        assert_eq!(3, Rc::strong_count(&tracker));
+        drop(list);
+        assert_eq!(1, Rc::strong_count(&tracker));
+    }
+
+    #[test]
+    fn test_interleave() {
+        let list = linked_list![1, 3, 5].interleave(linked_list![2, 4]);
+        assert_eq!(linked_list![1, 2, 3, 4, 5], list);
+        assert_eq!(Some(&5), tail_value(&list));
+
+        let list = linked_list![1].interleave(linked_list![2, 4, 6]);
+        assert_eq!(linked_list![1, 2, 4, 6], list);
+        assert_eq!(Some(&6), tail_value(&list));
+
+        assert_eq!(
+            linked_list![2, 4],
+            LinkedList::new().interleave(linked_list![2, 4])
+        );
+        assert_eq!(
+            linked_list![1],
+            linked_list![1].interleave(LinkedList::new())
+        );
+
+        // nodes from an arena stay alive in the new list
+        let mut arena_list = LinkedList::with_arena();
+        arena_list.extend([10, 30]);
+        let list = LinkedList::from([0]).interleave(arena_list);
+        assert_eq!(linked_list![0, 10, 30], list);
+    }
+
+    #[test]
+    fn test_binary_search() {
+        let list = linked_list![1, 3, 5, 7, 9, 11];
+        for (idx, value) in list.iter().enumerate() {
+            assert_eq!(Ok(idx), list.binary_search(value));
+        }
+
+        assert_eq!(Err(0), list.binary_search(&0));
+        assert_eq!(Err(2), list.binary_search(&4));
+        assert_eq!(Err(5), list.binary_search(&10));
+        assert_eq!(Err(6), list.binary_search(&12));
+        assert_eq!(Err(0), LinkedList::new().binary_search(&1));
+
+        // matches where slice::binary_search would insert
+        let values = [2, 4, 4, 4, 8];
+        let list = LinkedList::from(values);
+        for target in 0..10 {
+            match values.binary_search(&target) {
+                Ok(_) => assert_eq!(
+                    Some(&target),
+                    list.get(list.binary_search(&target).unwrap())
+                ),
+                Err(idx) => assert_eq!(Err(idx), list.binary_search(&target)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_traversal_interleaved_with_writes() {
+        let mut list: LinkedList<usize> = (0..6).collect();
+
+        // write through one node, then walk past it and write again, so a
+        // stale reference from the walk fails `cargo +nightly miri test`
+        *list.get_mut(2).unwrap() = 20;
+        assert_eq!(Some(&20), list.get(2));
+        list.insert(3, 30).unwrap();
+        *list.get_mut(3).unwrap() += 1;
+        assert_eq!(Some(&31), list.get(3));
+        assert_eq!(Ok(20), list.remove(2));
+        *list.get_mut(2).unwrap() += 1;
+        list.push_back(5);
+        list.dedup();
+
+        assert_eq!(linked_list![0, 1, 32, 3, 4, 5], list);
+        assert_eq!(Some(&5), tail_value(&list));
+    }
+}
+
+/// Sequences of removals that give Miri a chance to catch any use-after-free
+/// or invalid pointer dereference in `remove`.
+///
+/// Run with `cargo +nightly miri test remove_miri_tests`.
+#[cfg(test)]
+mod remove_miri_tests {
+    use super::*;
+
+    /// Builds a list of boxed values, so Miri tracks a separate allocation for each one.
+    fn boxed_list(len: usize) -> LinkedList<Box<usize>> {
+        (0..len).map(Box::new).collect()
+    }
+
+    /// Reads every value and the tail, so Miri checks each remaining pointer.
+    fn assert_values(list: &LinkedList<Box<usize>>, expected: &[usize]) {
+        assert_eq!(expected.len(), list.len());
+        for (idx, value) in expected.iter().enumerate() {
+            assert_eq!(Some(value), list.get(idx).map(|value| &**value));
+        }
+        assert_eq!(expected.last(), list.last().map(|value| &**value));
+    }
+
+    #[test]
+    fn test_remove_head() {
+        let mut list = boxed_list(4);
+        assert_eq!(Ok(Box::new(0)), list.remove(0));
+        assert_values(&list, &[1, 2, 3]);
+        list.push_front(Box::new(0));
+        assert_values(&list, &[0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_remove_middle() {
+        let mut list = boxed_list(5);
+        assert_eq!(Ok(Box::new(2)), list.remove(2));
+        assert_eq!(Ok(Box::new(1)), list.remove(1));
+        assert_values(&list, &[0, 3, 4]);
+        list.insert(1, Box::new(1)).unwrap();
+        assert_values(&list, &[0, 1, 3, 4]);
+    }
+
+    #[test]
+    fn test_remove_tail() {
+        let mut list = boxed_list(3);
+        assert_eq!(Ok(Box::new(2)), list.remove(2));
+        assert_values(&list, &[0, 1]);
+
+        // pushing after removing the tail goes through the cached tail pointer
+        list.push_back(Box::new(5));
+        assert_values(&list, &[0, 1, 5]);
+    }
+
+    #[test]
+    fn test_remove_until_empty() {
+        for start in 0..4 {
+            let mut list = boxed_list(4);
+            let mut expected: Vec<usize> = (0..4).collect();
+            let mut idx = start;
+            while !list.is_empty() {
+                idx %= list.len();
+                assert_eq!(Ok(Box::new(expected.remove(idx))), list.remove(idx));
+                assert_values(&list, &expected);
+            }
+            assert_eq!(Err(LinkedListError::EmptyList), list.remove(0));
+
+            list.push_back(Box::new(7));
+            assert_values(&list, &[7]);
+        }
+    }
+
+    #[test]
+    fn test_remove_with_arena() {
+        let mut list = LinkedList::with_arena();
+        list.extend((0..4).map(Box::new));
+        assert_eq!(Ok(Box::new(3)), list.remove(3));
+        assert_eq!(Ok(Box::new(0)), list.remove(0));
+        assert_eq!(Ok(Box::new(1)), list.remove(0));
+        assert_values(&list, &[2]);
+        assert_eq!(Ok(Box::new(2)), list.remove(0));
+        assert_values(&list, &[]);
+    }
+
+    #[test]
+    fn test_remove_freshly_allocated_nodes() {
+        // every way of allocating a node, each read and written before it is removed
+        let mut list = LinkedList::new();
+        list.push_back(Box::new(1));
+        list.push_front(Box::new(0));
+        list.add_at(Box::new(3), 2).unwrap();
+        list.add_at(Box::new(2), 2).unwrap();
+        **list.get_mut(2).unwrap() += 10;
+        assert_values(&list, &[0, 1, 12, 3]);
+
+        assert_eq!(Ok(Box::new(12)), list.remove(2));
+        list.push_back(Box::new(4));
+        assert_eq!(Ok(Box::new(0)), list.remove(0));
+        assert_values(&list, &[1, 3, 4]);
+        assert_eq!(Ok(Box::new(4)), list.remove(2));
+        assert_values(&list, &[1, 3]);
+    }
+}
+
+/// Checks that every operation which detaches nodes drops each value exactly
+/// once, so nothing leaks and nothing is freed twice.
+#[cfg(test)]
+mod leak_tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Value that counts how many times values sharing its counter were dropped.
+    struct DropCounter {
+        drops: Arc<AtomicUsize>,
+    }
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.drops.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    /// Builds a list of `len` counters that share one drop count.
+    ///
+    /// # Params
+    /// - `len` - The number of counters to create.
+    /// - `with_arena` - Whether the list allocates its nodes from an arena.
+    ///
+    /// # Returns
+    /// - The list, and the drop count its values share.
+    fn counter_list(len: usize, with_arena: bool) -> (LinkedList<DropCounter>, Arc<AtomicUsize>) {
+        let drops = Arc::new(AtomicUsize::new(0));
+        let mut list = if with_arena {
+            LinkedList::with_arena()
+        } else {
+            LinkedList::new()
+        };
+        for _ in 0..len {
+            list.push_back(DropCounter {
+                drops: Arc::clone(&drops),
+            });
+        }
+        (list, drops)
+    }
+
+    /// Runs a detaching operation on lists with and without an arena, then
+    /// checks that every value was dropped exactly once by the end.
+    ///
+    /// # Params
+    /// - `operation` - Detaches some of the nodes of the list it is given.
+    fn assert_no_leaks<F: Fn(&mut LinkedList<DropCounter>)>(operation: F) {
+        for with_arena in [false, true] {
+            let (mut list, drops) = counter_list(10, with_arena);
+            operation(&mut list);
+            let dropped_early = drops.load(Ordering::SeqCst);
+            assert_eq!(10 - list.len(), dropped_early);
+
+            drop(list);
+            assert_eq!(10, drops.load(Ordering::SeqCst));
+        }
+    }
+
+    #[test]
+    fn test_remove_no_leaks() {
+        assert_no_leaks(|list| {
+            drop(list.remove(9));
+            drop(list.remove(4));
+            drop(list.remove(0));
+        });
+    }
+
+    #[test]
+    fn test_pop_no_leaks() {
+        assert_no_leaks(|list| {
+            drop(list.pop_front());
+            drop(list.pop_back());
+            drop(list.pop_back());
+        });
+    }
+
+    #[test]
+    fn test_split_off_no_leaks() {
+        assert_no_leaks(|list| {
+            let mut back = list.split_off(6).unwrap();
+            drop(back.pop_front());
+            drop(back);
+        });
+        // the split off part outlives the list it came from
+        for with_arena in [false, true] {
+            let (mut list, drops) = counter_list(10, with_arena);
+            let back = list.split_off(3).unwrap();
+            drop(list);
+            assert_eq!(3, drops.load(Ordering::SeqCst));
+            drop(back);
+            assert_eq!(10, drops.load(Ordering::SeqCst));
         }
     }
+
+    #[test]
+    fn test_truncate_no_leaks() {
+        assert_no_leaks(|list| list.truncate(4));
+        assert_no_leaks(|list| list.truncate(0));
+    }
+
+    #[test]
+    fn test_clear_no_leaks() {
+        assert_no_leaks(|list| {
+            list.clear();
+            assert!(list.is_empty());
+        });
+    }
 }
@@ -0,0 +1,2 @@
+//! A simple singly linked list built on raw pointers.
+pub mod data_structure;
@@ -1,5 +1,4 @@
-mod data_structure;
-use data_structure::linked_list::LinkedList;
+use linked_list::data_structure::linked_list::LinkedList;
 
 fn main() {
     let mut list = LinkedList::<i32>::new();